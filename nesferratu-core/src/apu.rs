@@ -0,0 +1,313 @@
+mod channels;
+
+use std::collections::VecDeque;
+use channels::{Pulse, Triangle, Noise, Dmc};
+
+pub const CPU_CLOCK_RATE: f64 = 1_789_773.0;
+pub const DEFAULT_SAMPLE_RATE: u32 = 44_100;
+
+// frame counter steps in CPU cycles (NTSC)
+const STEP_1: u32 = 7457;
+const STEP_2: u32 = 14913;
+const STEP_3: u32 = 22371;
+const STEP_4: u32 = 29829;
+const STEP_5: u32 = 37281;
+
+// samples kept when nobody drains them, one second at the default rate, the oldest are dropped
+const MAX_BUFFERED_SAMPLES: usize = DEFAULT_SAMPLE_RATE as usize;
+
+pub struct Apu {
+    pulse1: Pulse,
+    pulse2: Pulse,
+    triangle: Triangle,
+    noise: Noise,
+    dmc: Dmc,
+
+    // frame counter
+    five_step_mode: bool,
+    irq_inhibit: bool,
+    frame_irq: bool,
+    frame_cycle: u32,
+    odd_cycle: bool,
+
+    // output, no samples are generated until a sample rate is set
+    sample_rate: Option<u32>,
+    sample_accumulator: f32,
+    sample_count: u32,
+    sample_clock: f64,
+    highpass_prev_in: f32,
+    highpass_prev_out: f32,
+    samples: VecDeque<f32>,
+}
+
+impl Apu {
+    pub fn new() -> Apu {
+        Apu {
+            pulse1: Pulse::new(true),
+            pulse2: Pulse::new(false),
+            triangle: Triangle::default(),
+            noise: Noise::new(),
+            dmc: Dmc::new(),
+
+            five_step_mode: false,
+            irq_inhibit: false,
+            frame_irq: false,
+            frame_cycle: 0,
+            odd_cycle: false,
+
+            sample_rate: None,
+            sample_accumulator: 0.0,
+            sample_count: 0,
+            sample_clock: 0.0,
+            highpass_prev_in: 0.0,
+            highpass_prev_out: 0.0,
+            samples: VecDeque::new(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.cpu_write(0x4015, 0x00);
+        self.frame_irq = false;
+        self.frame_cycle = 0;
+        self.odd_cycle = false;
+    }
+
+    // turns on sample output, headless callers that never listen can leave it off
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.sample_rate = Some(sample_rate);
+        self.sample_clock = 0.0;
+    }
+
+    pub fn sample_rate(&self) -> Option<u32> {
+        self.sample_rate
+    }
+
+    pub fn drain_samples(&mut self) -> Vec<f32> {
+        self.samples.drain(..).collect()
+    }
+
+    // level of the APU's IRQ output, the frame counter and DMC IRQs are wired together
+    pub fn irq(&self) -> bool {
+        self.frame_irq || self.dmc.irq
+    }
+
+    pub(crate) fn dmc_read_request(&self) -> Option<u16> {
+        self.dmc.read_request()
+    }
+
+    pub(crate) fn dmc_fill(&mut self, data: u8) {
+        self.dmc.fill(data);
+    }
+
+    // $4015 APU status
+    pub(crate) fn read_status(&mut self) -> u8 {
+        let mut status = 0;
+
+        status |= self.pulse1.active() as u8;
+        status |= (self.pulse2.active() as u8) << 1;
+        status |= (self.triangle.active() as u8) << 2;
+        status |= (self.noise.active() as u8) << 3;
+        status |= (self.dmc.active() as u8) << 4;
+        status |= (self.frame_irq as u8) << 6;
+        status |= (self.dmc.irq as u8) << 7;
+
+        // reading the status clears the frame interrupt flag
+        self.frame_irq = false;
+
+        status
+    }
+
+    pub(crate) fn cpu_write(&mut self, addr: u16, data: u8) {
+        match addr {
+            // $4000-$4003 pulse 1
+            0x4000..=0x4003 => self.pulse1.write(addr - 0x4000, data),
+            // $4004-$4007 pulse 2
+            0x4004..=0x4007 => self.pulse2.write(addr - 0x4004, data),
+            // $4008-$400B triangle
+            0x4008..=0x400B => self.triangle.write(addr - 0x4008, data),
+            // $400C-$400F noise
+            0x400C..=0x400F => self.noise.write(addr - 0x400C, data),
+            // $4010-$4013 DMC
+            0x4010..=0x4013 => self.dmc.write(addr - 0x4010, data),
+            // $4015 channel enable
+            0x4015 => {
+                self.pulse1.set_enabled(data & 0x01 != 0);
+                self.pulse2.set_enabled(data & 0x02 != 0);
+                self.triangle.set_enabled(data & 0x04 != 0);
+                self.noise.set_enabled(data & 0x08 != 0);
+                self.dmc.set_enabled(data & 0x10 != 0);
+            }
+            // $4017 frame counter
+            0x4017 => {
+                self.five_step_mode = data & 0x80 != 0;
+                self.irq_inhibit = data & 0x40 != 0;
+
+                if self.irq_inhibit {
+                    self.frame_irq = false;
+                }
+
+                self.frame_cycle = 0;
+
+                // the 5-step mode immediately clocks all units
+                if self.five_step_mode {
+                    self.clock_quarter_frame();
+                    self.clock_half_frame();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // clocked once every CPU cycle
+    pub(crate) fn clock(&mut self) {
+        self.triangle.clock_timer();
+        self.noise.clock_timer();
+        self.dmc.clock_timer();
+
+        // pulse channels are clocked every APU cycle, which is every other CPU cycle
+        if self.odd_cycle {
+            self.pulse1.clock_timer();
+            self.pulse2.clock_timer();
+        }
+        self.odd_cycle = !self.odd_cycle;
+
+        self.clock_frame_counter();
+        self.output_sample();
+    }
+
+    fn clock_frame_counter(&mut self) {
+        self.frame_cycle += 1;
+
+        match self.frame_cycle {
+            STEP_1 | STEP_3 => {
+                self.clock_quarter_frame();
+            }
+            STEP_2 => {
+                self.clock_quarter_frame();
+                self.clock_half_frame();
+            }
+            STEP_4 if !self.five_step_mode => {
+                self.clock_quarter_frame();
+                self.clock_half_frame();
+
+                if !self.irq_inhibit {
+                    self.frame_irq = true;
+                }
+
+                self.frame_cycle = 0;
+            }
+            STEP_5 if self.five_step_mode => {
+                self.clock_quarter_frame();
+                self.clock_half_frame();
+                self.frame_cycle = 0;
+            }
+            _ => {}
+        }
+    }
+
+    fn clock_quarter_frame(&mut self) {
+        self.pulse1.clock_quarter_frame();
+        self.pulse2.clock_quarter_frame();
+        self.triangle.clock_quarter_frame();
+        self.noise.clock_quarter_frame();
+    }
+
+    fn clock_half_frame(&mut self) {
+        self.pulse1.clock_half_frame();
+        self.pulse2.clock_half_frame();
+        self.triangle.clock_half_frame();
+        self.noise.clock_half_frame();
+    }
+
+    // non-linear mixer, see https://wiki.nesdev.org/w/index.php/APU_Mixer
+    fn mix(&self) -> f32 {
+        let pulse = (self.pulse1.output() + self.pulse2.output()) as f32;
+        let pulse_out = if pulse == 0.0 {
+            0.0
+        } else {
+            95.88 / (8128.0 / pulse + 100.0)
+        };
+
+        let tnd = self.triangle.output() as f32 / 8227.0
+                + self.noise.output() as f32 / 12241.0
+                + self.dmc.output() as f32 / 22638.0;
+        let tnd_out = if tnd == 0.0 {
+            0.0
+        } else {
+            159.79 / (1.0 / tnd + 100.0)
+        };
+
+        pulse_out + tnd_out
+    }
+
+    // averages the mixer output over all CPU cycles belonging to one output sample
+    fn output_sample(&mut self) {
+        let sample_rate = match self.sample_rate {
+            Some(rate) => rate,
+            None => return,
+        };
+
+        self.sample_accumulator += self.mix();
+        self.sample_count += 1;
+
+        self.sample_clock += sample_rate as f64;
+        if self.sample_clock < CPU_CLOCK_RATE {
+            return;
+        }
+        self.sample_clock -= CPU_CLOCK_RATE;
+
+        let sample = self.sample_accumulator / self.sample_count as f32;
+        self.sample_accumulator = 0.0;
+        self.sample_count = 0;
+
+        // first-order high-pass at ~90Hz to remove the DC offset, like the console's output stage
+        let alpha = 1.0 / (1.0 + 2.0 * std::f32::consts::PI * 90.0 / sample_rate as f32);
+        let out = alpha * (self.highpass_prev_out + sample - self.highpass_prev_in);
+        self.highpass_prev_in = sample;
+        self.highpass_prev_out = out;
+
+        if self.samples.len() == MAX_BUFFERED_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(out.clamp(-1.0, 1.0));
+    }
+}
+
+impl Default for Apu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_samples_until_a_sample_rate_is_set() {
+        let mut apu = Apu::new();
+        for _ in 0..10_000 {
+            apu.clock();
+        }
+        assert!(apu.drain_samples().is_empty());
+
+        apu.set_sample_rate(DEFAULT_SAMPLE_RATE);
+        for _ in 0..10_000 {
+            apu.clock();
+        }
+        // 10000 cycles at 44.1kHz are 246.4 samples
+        assert_eq!(apu.drain_samples().len(), 246);
+        assert!(apu.drain_samples().is_empty());
+    }
+
+    #[test]
+    fn undrained_samples_are_capped() {
+        let mut apu = Apu::new();
+        // one sample every other cycle fills the buffer quickly
+        apu.set_sample_rate((CPU_CLOCK_RATE / 2.0) as u32 + 1);
+        for _ in 0..(MAX_BUFFERED_SAMPLES * 3) {
+            apu.clock();
+        }
+        assert_eq!(apu.drain_samples().len(), MAX_BUFFERED_SAMPLES);
+    }
+}
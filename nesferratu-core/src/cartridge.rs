@@ -1,11 +1,91 @@
 pub mod mappers;
 
-use mappers::{Mapper, MappedCpuAddress};
+use mappers::{Mapper, MappedCpuAddress, MappedPpuAddress};
 
-use std::{io::{Result as IoResult, Read, Error, ErrorKind}, path::Path};
-use std::fs::File;
+use std::{fmt::Display, io::{self, Result as IoResult, Read}, path::{Path, PathBuf}};
+use std::fs::{self, File};
 
-use crate::CpuBusDevice;
+use crate::{CpuBusDevice, PpuBusDevice};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mirroring {
+    Horizontal,
+    Vertical,
+    SingleScreenLower,
+    SingleScreenUpper,
+    FourScreen,
+}
+
+#[derive(Debug)]
+pub enum CartridgeError {
+    Io(io::Error),
+    BadMagic,                                                   // not an iNES or NES 2.0 file
+    Truncated { section: &'static str, expected: usize, got: usize },
+    UnsupportedMapper { id: u16, submapper: u8 },
+    InvalidHeader(&'static str),                                // inconsistent header fields
+}
+
+impl Display for CartridgeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CartridgeError::Io(e) => write!(f, "{}", e),
+            CartridgeError::BadMagic => write!(f, "Not an iNES or NES 2.0 file"),
+            CartridgeError::Truncated { section, expected, got } => {
+                write!(f, "File is truncated, expected {} bytes of {} but got only {}", expected, section, got)
+            }
+            CartridgeError::UnsupportedMapper { id, submapper: 0 } => write!(f, "Mapper {} is not supported", id),
+            CartridgeError::UnsupportedMapper { id, submapper } => {
+                write!(f, "Mapper {} (submapper {}) is not supported", id, submapper)
+            }
+            CartridgeError::InvalidHeader(msg) => write!(f, "Invalid header: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CartridgeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CartridgeError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CartridgeError {
+    fn from(e: io::Error) -> Self {
+        CartridgeError::Io(e)
+    }
+}
+
+// reads exactly len bytes, reporting how much of the section was there if the file ends early
+// the buffer grows with the data actually read, len comes from the header and can't be trusted
+fn read_section<R: Read>(reader: &mut R, section: &'static str, len: usize) -> Result<Vec<u8>, CartridgeError> {
+    let mut data = Vec::new();
+    reader.take(len as u64).read_to_end(&mut data)?;
+
+    if data.len() < len {
+        return Err(CartridgeError::Truncated { section, expected: len, got: data.len() });
+    }
+
+    Ok(data)
+}
+
+// CPU/PPU timing the game was made for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Timing {
+    Ntsc,
+    Pal,
+    MultiRegion,
+    Dendy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConsoleType {
+    Nes,
+    VsSystem { ppu: u8, hardware: u8 },     // raw NES 2.0 PPU and hardware type
+    Playchoice10,
+    Extended(u8),                           // raw NES 2.0 extended console type
+}
 
 // iNES / NES2.0 header
 pub struct Header {
@@ -13,134 +93,310 @@ pub struct Header {
     prg_rom_size: usize,
     chr_rom_size: usize,
     mapper_id: u16,
+    submapper: u8,
     trainer_present: bool,
     persistent_memory_present: bool,
-    prg_ram_size: usize,
-    chr_ram_size: usize,
+    prg_ram_size: usize,        // volatile
+    prg_nvram_size: usize,      // battery-backed
+    chr_ram_size: usize,        // volatile
+    chr_nvram_size: usize,      // battery-backed
+    mirroring: Mirroring,
+    timing: Timing,
+    console_type: ConsoleType,
+    misc_rom_count: u8,
+    default_expansion_device: u8,
 }
 
-pub struct Cartridge {
-    header: Header,
-    mapper: Box<dyn Mapper>,
-    trainer: Option<Box<[u8]>>,
-    prg_rom: Box<[u8]>,
-    chr_rom: Box<[u8]>,
-    misc_rom: Box<[u8]>, 
-}
+impl Header {
+    // parses the 16 byte header at the start of an iNES or NES 2.0 file without reading the rest of it
+    pub fn parse(raw_header: &[u8]) -> Result<Header, CartridgeError> {
+        if raw_header.len() < 16 {
+            return Err(CartridgeError::Truncated { section: "header", expected: 16, got: raw_header.len() });
+        }
 
-impl Cartridge {
+        // check if file is valid iNES
+        if raw_header[0..4] != *"NES\u{1A}".as_bytes() {
+            return Err(CartridgeError::BadMagic);
+        }
+
+        // check if file is maybe actually in NES 2.0 format, see https://www.nesdev.org/wiki/NES_2.0
+        // header[7] must be xxxx 10xx
+        let is_nes20 = raw_header[7] & 0xC == 0x8;
+
+        let mut prg_rom_size = raw_header[4] as usize;
+        let mut chr_rom_size = raw_header[5] as usize;
+        let mut mapper = (raw_header[6] >> 4 | raw_header[7] & 0xF0) as u16;
+        let mut submapper = 0;
+        let trainer = raw_header[6] & 0x4 != 0;
+        let persistent_memory = raw_header[6] & 0x2 != 0;
+        let mirroring = if raw_header[6] & 0x8 != 0 {
+            // the cartridge provides extra VRAM for all four nametables
+            Mirroring::FourScreen
+        } else if raw_header[6] & 0x1 == 1 {
+            Mirroring::Vertical
+        } else {
+            Mirroring::Horizontal
+        };
+        let mut console_type = match raw_header[7] & 0x3 {
+            0 => ConsoleType::Nes,
+            1 => ConsoleType::VsSystem { ppu: 0, hardware: 0 },
+            2 => ConsoleType::Playchoice10,
+            _ => ConsoleType::Extended(0),
+        };
+        let mut timing = Timing::Ntsc;
+        let mut misc_rom_count = 0;
+        let mut default_expansion_device = 0;
+        let prg_ram_size;
+        let prg_nvram_size;
+        let chr_ram_size;
+        let chr_nvram_size;
+
+        if is_nes20 {
+            mapper |= ((raw_header[8] & 0x0F) as u16) << 8;
+            submapper = raw_header[8] >> 4;
+
+            // prg rom size is more complicated
+            let msb = raw_header[9] & 0x0F;
+
+            if msb != 0x0F {
+                // literal notation in 16KB units
+                prg_rom_size = (prg_rom_size | (msb as usize) << 8) * 16384;
+            } else {
+                // exponent-multiplier notation
+                let mul = prg_rom_size & 0x3;
+                let exp = prg_rom_size >> 2;
+
+                prg_rom_size = 2usize.checked_pow(exp as u32)
+                    .and_then(|size| size.checked_mul(mul * 2 + 1))
+                    .ok_or(CartridgeError::InvalidHeader("PRG ROM size out of range"))?;
+            }
+
+            // chr rom size aswell
+            let msb = raw_header[9] >> 4;
+
+            if msb != 0x0F {
+                // literal notation in 8KB units
+                chr_rom_size = (chr_rom_size | (msb as usize) << 8) * 8192;
+            } else {
+                // exponent-multiplier notation
+                let mul = chr_rom_size & 0x3;
+                let exp = chr_rom_size >> 2;
+
+                chr_rom_size = 2usize.checked_pow(exp as u32)
+                    .and_then(|size| size.checked_mul(mul * 2 + 1))
+                    .ok_or(CartridgeError::InvalidHeader("CHR ROM size out of range"))?;
+            }
+
+            // prg ram sizes, volatile in the low and non-volatile in the high nibble, a shift count of 0 means no RAM
+            let shift_size = |shift: u8| if shift == 0 { 0 } else { 64 << shift };
+            prg_ram_size = shift_size(raw_header[10] & 0x0F);
+            prg_nvram_size = shift_size(raw_header[10] >> 4);
+
+            // chr ram sizes, same encoding as prg ram
+            chr_ram_size = shift_size(raw_header[11] & 0x0F);
+            chr_nvram_size = shift_size(raw_header[11] >> 4);
+
+            timing = match raw_header[12] & 0x3 {
+                0 => Timing::Ntsc,
+                1 => Timing::Pal,
+                2 => Timing::MultiRegion,
+                _ => Timing::Dendy,
+            };
+
+            console_type = match console_type {
+                ConsoleType::VsSystem { .. } => ConsoleType::VsSystem {
+                    ppu: raw_header[13] & 0x0F,
+                    hardware: raw_header[13] >> 4,
+                },
+                ConsoleType::Extended(_) => ConsoleType::Extended(raw_header[13] & 0x0F),
+                other => other,
+            };
+
+            misc_rom_count = raw_header[14] & 0x3;
+            default_expansion_device = raw_header[15] & 0x3F;
+        } else {
+            prg_rom_size *= 16384;
+            chr_rom_size *= 8192 ;
+
+            // byte 8 holds the prg ram size in 8KB units, 0 infers 8KB for compatibility
+            let size = (raw_header[8] as usize).max(1) * 8192;
+            if persistent_memory {
+                prg_ram_size = 0;
+                prg_nvram_size = size;
+            } else {
+                prg_ram_size = size;
+                prg_nvram_size = 0;
+            }
+
+            // iNES can't declare CHR RAM, boards without CHR ROM are assumed to have 8KB
+            chr_ram_size = if chr_rom_size == 0 { 8192 } else { 0 };
+            chr_nvram_size = 0;
+        }
 
-    pub fn read_from_file<P: AsRef<Path>>(path: P) -> IoResult<Cartridge> {
-        let file = File::open(path)?;
-        Self::read(file)
+        Ok(Header {
+            is_nes20,
+            prg_rom_size,
+            chr_rom_size,
+            mapper_id: mapper,
+            submapper,
+            trainer_present: trainer,
+            persistent_memory_present: persistent_memory,
+            prg_ram_size,
+            prg_nvram_size,
+            chr_ram_size,
+            chr_nvram_size,
+            mirroring,
+            timing,
+            console_type,
+            misc_rom_count,
+            default_expansion_device,
+        })
     }
 
-    pub fn read<R: Read>(mut reader: R) -> IoResult<Cartridge> {
-        let mut raw_header = [0u8; 16];
-        reader.read(&mut raw_header)?;
-        
+    pub fn has_battery(&self) -> bool {
+        self.persistent_memory_present
+    }
 
-        // check if file is valid iNES
-        if !(raw_header[0..4] == *"NES\u{1A}".as_bytes()) {
-            return Err(Error::new(ErrorKind::InvalidData, "Input Data is not in iNES format"));
-        }
-
-        // parse header
-        let header = {
-            // check if file is maybe actually in NES 2.0 format
-            // header[7] must be xxxx 10xx
-            let is_nes20 = raw_header[7] & 0xC == 0x8;
-
-            let mut prg_rom_size = raw_header[4] as usize;
-            let mut chr_rom_size = raw_header[5] as usize;
-            let mapper = (raw_header[6] >> 4 | raw_header[7] & 0xF0) as u16;
-            let trainer = (raw_header[6] >> 2) == 1;
-            let persistent_memory = (raw_header[6] >> 1) == 1;
-            let mut prg_ram_size = 0;
-            let chr_ram_size;
-
-            if is_nes20 {
-                // prg rom size is more complicated
-                let b9 = raw_header[9] & 0xF0;
-
-                if b9 != 0xF0 {
-                    // literal notation
-                    prg_rom_size |= (b9 as usize) << 4;
-                } else {
-                    // exponent-multiplier notation
-                    let mul = prg_rom_size & 0x3;
-                    let exp = prg_rom_size >> 2;
+    pub fn is_nes20(&self) -> bool {
+        self.is_nes20
+    }
 
-                    prg_rom_size = 2usize.pow(exp as u32) * (mul * 2 + 1);
-                }
+    pub fn mapper_id(&self) -> u16 {
+        self.mapper_id
+    }
 
-                // chr rom size aswell
-                let b9 = raw_header[9] & 0x0F;
+    // always 0 for iNES files
+    pub fn submapper(&self) -> u8 {
+        self.submapper
+    }
 
-                if b9 != 0x0F {
-                    // literal notation
-                    chr_rom_size |= (b9 as usize) << 8
-                } else {
-                    // exponent-multiplier notation
-                    let mul = chr_rom_size & 0x3;
-                    let exp = chr_rom_size >> 2;
+    pub fn prg_rom_size(&self) -> usize {
+        self.prg_rom_size
+    }
 
-                    chr_rom_size = 2usize.pow(exp as u32) * (mul * 2 + 1);
-                }
+    pub fn chr_rom_size(&self) -> usize {
+        self.chr_rom_size
+    }
 
-                // prg ram size
-                prg_ram_size = 64 << (raw_header[10] & 0x0F);
+    pub fn prg_ram_size(&self) -> usize {
+        self.prg_ram_size
+    }
 
-                // chr ram size
-                chr_ram_size = 64 << (raw_header[11] & 0x0F);
+    pub fn prg_nvram_size(&self) -> usize {
+        self.prg_nvram_size
+    }
 
+    pub fn chr_ram_size(&self) -> usize {
+        self.chr_ram_size
+    }
 
-            } else {
-                prg_rom_size *= 16384;
-                chr_rom_size *= 8192 ;
+    pub fn chr_nvram_size(&self) -> usize {
+        self.chr_nvram_size
+    }
 
-                if persistent_memory {
-                    prg_ram_size = 8192;
-                }
+    pub fn has_trainer(&self) -> bool {
+        self.trainer_present
+    }
 
-                chr_ram_size = 8192;
-            }
+    // the mapper might control mirroring at runtime, see Cartridge::mirroring
+    pub fn mirroring(&self) -> Mirroring {
+        self.mirroring
+    }
+
+    pub fn timing(&self) -> Timing {
+        self.timing
+    }
+
+    pub fn console_type(&self) -> ConsoleType {
+        self.console_type
+    }
+
+    pub fn misc_rom_count(&self) -> u8 {
+        self.misc_rom_count
+    }
+
+    // raw NES 2.0 default expansion device, 0 if unspecified
+    pub fn default_expansion_device(&self) -> u8 {
+        self.default_expansion_device
+    }
+}
+
+pub struct Cartridge {
+    header: Header,
+    mapper: Box<dyn Mapper>,
+    trainer: Option<Box<[u8]>>,
+    prg_rom: Box<[u8]>,
+    chr_rom: Box<[u8]>,
+    misc_rom: Box<[u8]>,
+    prg_ram: Box<[u8]>,
+    chr_ram: Box<[u8]>,
+    save_path: Option<PathBuf>,
+}
+
+impl Cartridge {
+
+    // battery-backed cartridges load and store their save file next to the ROM, e.g. game.nes -> game.sav
+    pub fn read_from_file<P: AsRef<Path>>(path: P) -> Result<Cartridge, CartridgeError> {
+        let file = File::open(&path)?;
+        let mut cartridge = Self::read(file)?;
 
-            Header {
-                is_nes20,
-                prg_rom_size,
-                chr_rom_size,
-                mapper_id: mapper,
-                trainer_present: trainer,
-                persistent_memory_present: persistent_memory,
-                prg_ram_size,
-                chr_ram_size,
+        if cartridge.has_battery() {
+            let save_path = path.as_ref().with_extension("sav");
+
+            if save_path.exists() {
+                let data = fs::read(&save_path)?;
+                cartridge.load_save(&data);
             }
-        };
+
+            cartridge.save_path = Some(save_path);
+        }
+
+        Ok(cartridge)
+    }
+
+    pub fn read<R: Read>(mut reader: R) -> Result<Cartridge, CartridgeError> {
+        let raw_header = read_section(&mut reader, "header", 16)?;
+        let header = Header::parse(&raw_header)?;
+
+        if header.prg_rom_size == 0 {
+            return Err(CartridgeError::InvalidHeader("no PRG ROM"));
+        }
 
         // get mapper instance
-        let mapper = mappers::map_mapper(header.mapper_id).expect("Unimplemented Mapper");
+        let mapper = match mappers::map_mapper(header.mapper_id) {
+            Some(mapper) => mapper,
+            None => return Err(CartridgeError::UnsupportedMapper { id: header.mapper_id, submapper: header.submapper }),
+        };
+
+        if !mapper.supports_prg_rom_size(&header) {
+            return Err(CartridgeError::InvalidHeader("PRG ROM size not supported by the mapper"));
+        }
 
         // read trainer, if present
         let trainer: Option<Box<[u8]>> = if header.trainer_present {
-            let mut data = [0u8; 512];
-            reader.read_exact(&mut data)?;
-            Some(Box::new(data))
+            Some(read_section(&mut reader, "trainer", 512)?.into_boxed_slice())
         } else {
             None
         };
 
         // read prg rom
-        let mut prg_rom = vec![0u8; header.prg_rom_size];
-        reader.read_exact(&mut prg_rom)?;
+        let prg_rom = read_section(&mut reader, "PRG ROM", header.prg_rom_size)?;
 
         //read chr rom
-        let mut chr_rom = vec![0u8; header.chr_rom_size];
-        reader.read_exact(&mut chr_rom)?;
+        let chr_rom = read_section(&mut reader, "CHR ROM", header.chr_rom_size)?;
 
         let mut misc_rom = Vec::new();
         reader.read_to_end(&mut misc_rom)?;
 
+        let prg_ram = vec![0u8; header.prg_ram_size + header.prg_nvram_size];
+
+        // boards without CHR ROM have at least 8KB of CHR RAM
+        let chr_ram = if header.chr_rom_size == 0 {
+            vec![0u8; (header.chr_ram_size + header.chr_nvram_size).max(0x2000)]
+        } else {
+            Vec::new()
+        };
+
         Ok(
             Cartridge {
                 header,
@@ -149,33 +405,325 @@ impl Cartridge {
                 prg_rom: prg_rom.into_boxed_slice(),
                 chr_rom: chr_rom.into_boxed_slice(),
                 misc_rom: misc_rom.into_boxed_slice(),
+                prg_ram: prg_ram.into_boxed_slice(),
+                chr_ram: chr_ram.into_boxed_slice(),
+                save_path: None,
             }
         )
-    } 
+    }
+
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    pub fn has_battery(&self) -> bool {
+        self.header.has_battery()
+    }
+
+    pub fn trainer(&self) -> Option<&[u8]> {
+        self.trainer.as_deref()
+    }
+
+    pub fn prg_rom(&self) -> &[u8] {
+        &self.prg_rom
+    }
+
+    pub fn chr_rom(&self) -> &[u8] {
+        &self.chr_rom
+    }
+
+    // everything after the CHR ROM, e.g. PlayChoice-10 INST-ROM
+    pub fn misc_rom(&self) -> &[u8] {
+        &self.misc_rom
+    }
+
+    // contents of the PRG RAM, for battery-backed cartridges this is the save data
+    pub fn save_data(&self) -> &[u8] {
+        &self.prg_ram
+    }
+
+    // replaces the start of the PRG RAM with the given save data
+    pub fn load_save(&mut self, data: &[u8]) {
+        let len = data.len().min(self.prg_ram.len());
+        self.prg_ram[..len].copy_from_slice(&data[..len]);
+    }
+
+    pub fn save_path(&self) -> Option<&Path> {
+        self.save_path.as_deref()
+    }
+
+    // None disables save files, e.g. when the save data is managed by the frontend
+    pub fn set_save_path<P: AsRef<Path>>(&mut self, path: Option<P>) {
+        self.save_path = path.map(|p| p.as_ref().to_path_buf());
+    }
+
+    // writes the PRG RAM to the save file, does nothing for cartridges without a battery
+    pub fn flush(&self) -> IoResult<()> {
+        match &self.save_path {
+            Some(path) if self.has_battery() => fs::write(path, &self.prg_ram),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn mirroring(&self) -> Mirroring {
+        self.mapper.mirroring(&self.header)
+    }
+
+    // reads PRG RAM without side effects, None if the address isn't mapped to RAM
+    pub(crate) fn peek_prg_ram(&self, addr: u16) -> Option<u8> {
+        match self.mapper.map_cpu(&self.header, addr) {
+            MappedCpuAddress::PrgRam(addr) if !self.prg_ram.is_empty() => Some(self.prg_ram[addr % self.prg_ram.len()]),
+            _ => None,
+        }
+    }
+
+    // writes to PRG RAM without reaching the mapper registers, false if the address isn't mapped to RAM
+    pub(crate) fn poke_prg_ram(&mut self, addr: u16, data: u8) -> bool {
+        match self.mapper.map_cpu(&self.header, addr) {
+            MappedCpuAddress::PrgRam(addr) if !self.prg_ram.is_empty() => {
+                let len = self.prg_ram.len();
+                self.prg_ram[addr % len] = data;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // called once every CPU cycle
+    pub fn cpu_clock(&mut self) {
+        self.mapper.cpu_clock();
+    }
+
+    // lets the mapper observe every access on the PPU bus, including nametables
+    pub fn ppu_address(&mut self, addr: u16) {
+        self.mapper.ppu_address(addr);
+    }
+
+    pub fn irq(&self) -> bool {
+        self.mapper.irq()
+    }
+}
+
+impl Drop for Cartridge {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            eprintln!("Could not write save file: {}", e);
+        }
+    }
 }
 
 impl CpuBusDevice for Cartridge {
     fn cpu_read(&self, addr: u16) -> u8 {
         match self.mapper.map_cpu(&self.header, addr) {
-            MappedCpuAddress::PrgRam(_) => {
-                panic!("The fuck is a cartridge RAM?");
+            MappedCpuAddress::PrgRam(addr) => {
+                if self.prg_ram.is_empty() {
+                    0x00
+                } else {
+                    self.prg_ram[addr % self.prg_ram.len()]
+                }
             }
             MappedCpuAddress::PrgRom(addr) => {
-                self.prg_rom[addr as usize]
+                self.prg_rom[addr % self.prg_rom.len()]
             }
             MappedCpuAddress::None => 0x00,
         }
     }
 
     fn cpu_write(&mut self, addr: u16, data: u8) {
-        match self.mapper.map_cpu(&self.header, addr) {
-            MappedCpuAddress::PrgRam(_) => {
-                panic!("The fuck is a cartridge RAM?");
+        // on boards with bus conflicts the ROM drives the bus at the same time as the CPU
+        let data = match self.mapper.map_cpu(&self.header, addr) {
+            MappedCpuAddress::PrgRom(rom_addr) if self.mapper.bus_conflicts() => data & self.prg_rom[rom_addr % self.prg_rom.len()],
+            _ => data,
+        };
+
+        match self.mapper.map_cpu_write(&self.header, addr, data) {
+            MappedCpuAddress::PrgRam(addr) => {
+                if !self.prg_ram.is_empty() {
+                    let len = self.prg_ram.len();
+                    self.prg_ram[addr % len] = data;
+                }
             }
-            MappedCpuAddress::PrgRom(addr) => {
-                self.prg_rom[addr as usize] = data;
+            MappedCpuAddress::PrgRom(_) | MappedCpuAddress::None => {},
+        }
+    }
+}
+
+impl PpuBusDevice for Cartridge {
+    fn ppu_read(&self, addr: u16) -> u8 {
+        match self.mapper.map_ppu(&self.header, addr) {
+            MappedPpuAddress::ChrRom(addr) => {
+                self.chr_rom[addr]
+            }
+            MappedPpuAddress::ChrRam(addr) => {
+                if self.chr_ram.is_empty() {
+                    0x00
+                } else {
+                    self.chr_ram[addr % self.chr_ram.len()]
+                }
             }
-            MappedCpuAddress::None => {},
+            MappedPpuAddress::None => 0x00,
         }
     }
-}
\ No newline at end of file
+
+    fn ppu_write(&mut self, addr: u16, data: u8) {
+        match self.mapper.map_ppu(&self.header, addr) {
+            MappedPpuAddress::ChrRam(addr) => {
+                if !self.chr_ram.is_empty() {
+                    let len = self.chr_ram.len();
+                    self.chr_ram[addr % len] = data;
+                }
+            }
+            MappedPpuAddress::ChrRom(_) | MappedPpuAddress::None => {}, // CHR ROM is read-only
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // header with the given bytes 4-15 after the magic
+    fn raw_header(fields: [u8; 12]) -> Vec<u8> {
+        let mut raw = b"NES\x1A".to_vec();
+        raw.extend_from_slice(&fields);
+        raw
+    }
+
+    // header followed by the PRG and CHR ROM it declares, filled with their offset
+    fn image(fields: [u8; 12]) -> Vec<u8> {
+        let mut image = raw_header(fields);
+        let header = Header::parse(&image).unwrap();
+        let len = header.prg_rom_size() + header.chr_rom_size();
+        image.extend((0..len).map(|i| i as u8));
+        image
+    }
+
+    #[test]
+    fn ines_header() {
+        let header = Header::parse(&raw_header([2, 1, 0x11, 0x40, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+        assert!(!header.is_nes20());
+        assert_eq!(header.mapper_id(), 0x41);
+        assert_eq!(header.submapper(), 0);
+        assert_eq!(header.prg_rom_size(), 0x8000);
+        assert_eq!(header.chr_rom_size(), 0x2000);
+        assert_eq!(header.mirroring(), Mirroring::Vertical);
+        assert!(!header.has_battery());
+        assert!(!header.has_trainer());
+
+        // byte 8 of 0 means 8KB of PRG RAM, iNES can't declare CHR RAM next to CHR ROM
+        assert_eq!(header.prg_ram_size(), 0x2000);
+        assert_eq!(header.prg_nvram_size(), 0);
+        assert_eq!(header.chr_ram_size(), 0);
+    }
+
+    #[test]
+    fn ines_ram_battery_and_trainer() {
+        let header = Header::parse(&raw_header([1, 0, 0x0E, 0, 4, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+        assert!(header.has_battery());
+        assert!(header.has_trainer());
+        assert_eq!(header.mirroring(), Mirroring::FourScreen);
+        assert_eq!(header.prg_ram_size(), 0);
+        assert_eq!(header.prg_nvram_size(), 0x8000);
+        assert_eq!(header.chr_ram_size(), 0x2000);
+    }
+
+    #[test]
+    fn nes20_literal_sizes() {
+        // the low nibble of byte 9 extends the PRG ROM size, the high nibble the CHR ROM size
+        let header = Header::parse(&raw_header([0x02, 0x01, 0x00, 0x08, 0x35, 0x21, 0x07, 0x70, 0, 0, 0, 0])).unwrap();
+
+        assert!(header.is_nes20());
+        assert_eq!(header.mapper_id(), 0x500);
+        assert_eq!(header.submapper(), 3);
+        assert_eq!(header.prg_rom_size(), 0x102 * 0x4000);
+        assert_eq!(header.chr_rom_size(), 0x201 * 0x2000);
+        assert_eq!(header.prg_ram_size(), 64 << 7);
+        assert_eq!(header.prg_nvram_size(), 0);
+        assert_eq!(header.chr_ram_size(), 0);
+        assert_eq!(header.chr_nvram_size(), 64 << 7);
+    }
+
+    #[test]
+    fn nes20_exponent_sizes() {
+        // 2^13 * 3 bytes of PRG ROM, 2^10 * 1 bytes of CHR ROM
+        let header = Header::parse(&raw_header([13 << 2 | 1, 10 << 2, 0, 0x08, 0, 0xFF, 0, 0, 0, 0, 0, 0])).unwrap();
+        assert_eq!(header.prg_rom_size(), 0x6000);
+        assert_eq!(header.chr_rom_size(), 0x400);
+
+        // 2^63 * 3 doesn't fit
+        let result = Header::parse(&raw_header([63 << 2 | 1, 0, 0, 0x08, 0, 0x0F, 0, 0, 0, 0, 0, 0]));
+        assert!(matches!(result, Err(CartridgeError::InvalidHeader(_))));
+    }
+
+    #[test]
+    fn nes20_timing_and_console_type() {
+        let header = Header::parse(&raw_header([1, 1, 0, 0x09, 0, 0, 0, 0, 0x01, 0x21, 0x02, 0x2A])).unwrap();
+
+        assert_eq!(header.timing(), Timing::Pal);
+        assert_eq!(header.console_type(), ConsoleType::VsSystem { ppu: 1, hardware: 2 });
+        assert_eq!(header.misc_rom_count(), 2);
+        assert_eq!(header.default_expansion_device(), 0x2A);
+    }
+
+    #[test]
+    fn header_errors() {
+        let result = Header::parse(&raw_header([0; 12])[..10]);
+        assert!(matches!(result, Err(CartridgeError::Truncated { section: "header", expected: 16, got: 10 })));
+
+        let result = Header::parse(b"NES\x00\x01\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00");
+        assert!(matches!(result, Err(CartridgeError::BadMagic)));
+    }
+
+    #[test]
+    fn cartridge_errors() {
+        let result = Cartridge::read(&raw_header([0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])[..]);
+        assert!(matches!(result, Err(CartridgeError::InvalidHeader("no PRG ROM"))));
+
+        let result = Cartridge::read(&raw_header([1, 0, 0, 0x08, 0x35, 0, 0, 0, 0, 0, 0, 0])[..]);
+        assert!(matches!(result, Err(CartridgeError::UnsupportedMapper { id: 0x500, submapper: 3 })));
+
+        let mut short = raw_header([1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        short.resize(16 + 0x1000, 0);
+        let result = Cartridge::read(&short[..]);
+        assert!(matches!(result, Err(CartridgeError::Truncated { section: "PRG ROM", expected: 0x4000, got: 0x1000 })));
+
+        // 2^40 bytes of PRG ROM for UxROM, nothing may be allocated from the header size
+        let result = Cartridge::read(&raw_header([40 << 2, 0, 0x20, 0x08, 0, 0x0F, 0, 0, 0, 0, 0, 0])[..]);
+        assert!(matches!(result, Err(CartridgeError::InvalidHeader(_))));
+
+        // 2MB for UxROM is fine, but the file ends right after the header
+        let result = Cartridge::read(&raw_header([21 << 2, 0, 0x20, 0x08, 0, 0x0F, 0, 0, 0, 0, 0, 0])[..]);
+        assert!(matches!(result, Err(CartridgeError::Truncated { section: "PRG ROM", expected: 0x200000, got: 0 })));
+
+        // 24KB isn't a whole number of 16KB banks
+        let result = Cartridge::read(&image([13 << 2 | 1, 0, 0x20, 0x08, 0, 0x0F, 0, 0, 0, 0, 0, 0])[..]);
+        assert!(matches!(result, Err(CartridgeError::InvalidHeader("PRG ROM size not supported by the mapper"))));
+    }
+
+    #[test]
+    fn sections() {
+        let mut raw = raw_header([1, 1, 0x04, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        raw.extend(std::iter::repeat_n(0xAA, 512));
+        raw.extend(std::iter::repeat_n(0xBB, 0x4000));
+        raw.extend(std::iter::repeat_n(0xCC, 0x2000));
+        raw.extend([1, 2, 3]);
+
+        let cartridge = Cartridge::read(&raw[..]).unwrap();
+        assert_eq!(cartridge.trainer().unwrap(), &[0xAA; 512][..]);
+        assert!(cartridge.prg_rom().iter().all(|&b| b == 0xBB));
+        assert!(cartridge.chr_rom().iter().all(|&b| b == 0xCC));
+        assert_eq!(cartridge.misc_rom(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn small_prg_rom_is_mirrored() {
+        // 8KB NROM, the reset vector at $FFFC comes from the end of the only 8KB
+        let cartridge = Cartridge::read(&image([13 << 2, 1, 0, 0x08, 0, 0x0F, 0, 0, 0, 0, 0, 0])[..]).unwrap();
+
+        assert_eq!(cartridge.cpu_read(0x8000), 0x00);
+        assert_eq!(cartridge.cpu_read(0xA001), 0x01);
+        assert_eq!(cartridge.cpu_read(0xFFFC), 0xFC);
+        assert_eq!(cartridge.cpu_read(0xFFFD), 0xFD);
+    }
+}
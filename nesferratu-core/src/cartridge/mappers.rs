@@ -1,29 +1,106 @@
-use crate::cartridge::Header;
+mod mapper001;
+mod mapper002;
+mod mapper003;
+mod mapper004;
+mod mapper007;
+mod mapper011;
+mod mapper034;
+mod mapper066;
+
+use crate::cartridge::{Header, Mirroring};
+
+use mapper001::Mapper001;
+use mapper002::Mapper002;
+use mapper003::Mapper003;
+use mapper004::Mapper004;
+use mapper007::Mapper007;
+use mapper011::Mapper011;
+use mapper034::Mapper034;
+use mapper066::Mapper066;
 
 pub trait Mapper {
     fn map_cpu(&self, meta: &Header, addr: u16) -> MappedCpuAddress;
+
+    // writes may change the state of the mapper, returns where the written data ends up
+    fn map_cpu_write(&mut self, meta: &Header, addr: u16, data: u8) -> MappedCpuAddress;
+
     fn map_ppu(&self, meta: &Header, addr: u16) -> MappedPpuAddress;
+
+    // checked when the cartridge is built, see prg_rom_fits
+    fn supports_prg_rom_size(&self, meta: &Header) -> bool;
+
+    // called for every address the PPU puts on its bus
+    fn ppu_address(&mut self, _addr: u16) {}
+
+    fn mirroring(&self, meta: &Header) -> Mirroring {
+        meta.mirroring
+    }
+
+    // called once every CPU cycle
+    fn cpu_clock(&mut self) {}
+
+    // boards without a write enable on the ROM see the AND of the written value and the ROM contents
+    fn bus_conflicts(&self) -> bool {
+        false
+    }
+
+    // level of the mapper's IRQ output
+    fn irq(&self) -> bool {
+        false
+    }
 }
 
+#[derive(Debug, PartialEq)]
 pub enum MappedCpuAddress {
-    PrgRam(u16),
-    PrgRom(u16),
+    PrgRam(usize),
+    PrgRom(usize),
     None,
 }
 
+#[derive(Debug, PartialEq)]
 pub enum MappedPpuAddress {
-    ChrRam(u16),
-    ChrRom(u16),
+    ChrRam(usize),
+    ChrRom(usize),
     None,
 }
 
 pub fn map_mapper(mapper_id: u16) -> Option<Box<dyn Mapper>> {
     match mapper_id {
-        000 => Some(Box::new(Mapper000{})),
+        0 => Some(Box::new(Mapper000{})),
+        1 => Some(Box::new(Mapper001::new())),
+        2 => Some(Box::new(Mapper002::new())),
+        3 => Some(Box::new(Mapper003::new())),
+        4 => Some(Box::new(Mapper004::new())),
+        7 => Some(Box::new(Mapper007::new())),
+        11 => Some(Box::new(Mapper011::new())),
+        34 => Some(Box::new(Mapper034::new())),
+        66 => Some(Box::new(Mapper066::new())),
         _ => None,
     }
 }
 
+// maps into CHR ROM, or CHR RAM if the cartridge has no CHR ROM
+fn chr_address(meta: &Header, offset: usize) -> MappedPpuAddress {
+    if meta.chr_rom_size == 0 {
+        MappedPpuAddress::ChrRam(offset)
+    } else {
+        MappedPpuAddress::ChrRom(offset % meta.chr_rom_size)
+    }
+}
+
+// the PRG ROM has to be a whole number of banks, or evenly fill one bank by mirroring,
+// and can't be larger than what the bank registers reach
+fn prg_rom_fits(meta: &Header, bank_size: usize, max_size: usize) -> bool {
+    let size = meta.prg_rom_size;
+    size > 0 && size <= max_size && (size.is_multiple_of(bank_size) || bank_size.is_multiple_of(size))
+}
+
+// PRG ROM switchable in 32KB banks at $8000-$FFFF
+fn prg_32k_address(meta: &Header, bank: usize, addr: u16) -> MappedCpuAddress {
+    let banks = (meta.prg_rom_size / 0x8000).max(1);
+    MappedCpuAddress::PrgRom((bank % banks) * 0x8000 + (addr & 0x7FFF) as usize)
+}
+
 pub struct Mapper000 {}
 
 impl Mapper for Mapper000 {
@@ -31,22 +108,200 @@ impl Mapper for Mapper000 {
         match addr {
             x if x >= 0xC000 => {
                 if meta.prg_rom_size > 0x4000 {
-                    MappedCpuAddress::PrgRom(addr - 0x8000)
+                    MappedCpuAddress::PrgRom((addr - 0x8000) as usize)
                 } else {
-                    MappedCpuAddress::PrgRom(addr - 0xC000) // mirror first half of ROM
+                    MappedCpuAddress::PrgRom((addr - 0xC000) as usize) // mirror first half of ROM
                 }
             }
             x if x >= 0x8000 => {
-                MappedCpuAddress::PrgRom(addr - 0x8000)
+                MappedCpuAddress::PrgRom((addr - 0x8000) as usize)
             }
             x if x >= 0x6000 => {
-                MappedCpuAddress::PrgRam(addr - 0x6000)
+                MappedCpuAddress::PrgRam((addr - 0x6000) as usize)
             }
             _ => MappedCpuAddress::None,
         }
     }
 
+    fn map_cpu_write(&mut self, meta: &Header, addr: u16, _data: u8) -> MappedCpuAddress {
+        match self.map_cpu(meta, addr) {
+            MappedCpuAddress::PrgRam(addr) => MappedCpuAddress::PrgRam(addr),
+            _ => MappedCpuAddress::None, // ROM is read-only
+        }
+    }
+
     fn map_ppu(&self, meta: &Header, addr: u16) -> MappedPpuAddress {
-        todo!()
+        match addr {
+            x if x < 0x2000 => chr_address(meta, addr as usize),
+            _ => MappedPpuAddress::None,
+        }
+    }
+
+    // 16KB or 32KB, smaller ROMs are mirrored
+    fn supports_prg_rom_size(&self, meta: &Header) -> bool {
+        prg_rom_fits(meta, 0x8000, 0x8000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // iNES header for the mapper with PRG ROM in 16KB and CHR ROM in 8KB units
+    pub(super) fn ines(mapper: u8, prg_banks: u8, chr_banks: u8) -> Header {
+        let raw = [b'N', b'E', b'S', 0x1A, prg_banks, chr_banks, mapper << 4, mapper & 0xF0, 0, 0, 0, 0, 0, 0, 0, 0];
+        Header::parse(&raw).unwrap()
+    }
+
+    // NES 2.0 header with a submapper
+    fn nes20(mapper: u8, submapper: u8, prg_banks: u8, chr_banks: u8) -> Header {
+        let raw = [b'N', b'E', b'S', 0x1A, prg_banks, chr_banks, mapper << 4, mapper & 0xF0 | 0x08, submapper << 4, 0, 0, 0, 0, 0, 0, 0];
+        Header::parse(&raw).unwrap()
+    }
+
+    fn mapper(meta: &Header) -> Box<dyn Mapper> {
+        map_mapper(meta.mapper_id).unwrap()
+    }
+
+    fn prg(mapper: &dyn Mapper, meta: &Header, addr: u16) -> usize {
+        match mapper.map_cpu(meta, addr) {
+            MappedCpuAddress::PrgRom(offset) => offset,
+            other => panic!("${:04X} is mapped to {:?}", addr, other),
+        }
+    }
+
+    fn chr(mapper: &dyn Mapper, meta: &Header, addr: u16) -> usize {
+        match mapper.map_ppu(meta, addr) {
+            MappedPpuAddress::ChrRom(offset) => offset,
+            other => panic!("${:04X} is mapped to {:?}", addr, other),
+        }
+    }
+
+    #[test]
+    fn nrom() {
+        let meta = ines(0, 1, 1);
+        let m = mapper(&meta);
+
+        // 16KB is mirrored into $C000-$FFFF
+        assert_eq!(prg(&*m, &meta, 0x8000), 0x0000);
+        assert_eq!(prg(&*m, &meta, 0xC123), 0x0123);
+        assert_eq!(m.map_cpu(&meta, 0x6001), MappedCpuAddress::PrgRam(1));
+        assert_eq!(m.map_cpu(&meta, 0x4020), MappedCpuAddress::None);
+
+        let meta = ines(0, 2, 1);
+        assert_eq!(prg(&*m, &meta, 0xC123), 0x4123);
+        assert_eq!(chr(&*m, &meta, 0x1FFF), 0x1FFF);
+        assert_eq!(m.map_ppu(&meta, 0x2000), MappedPpuAddress::None);
+
+        // boards without CHR ROM get CHR RAM
+        let meta = ines(0, 2, 0);
+        assert_eq!(m.map_ppu(&meta, 0x0123), MappedPpuAddress::ChrRam(0x0123));
+    }
+
+    #[test]
+    fn prg_rom_sizes() {
+        assert!(prg_rom_fits(&ines(2, 8, 0), 0x4000, 0x40000));
+        assert!(!prg_rom_fits(&ines(2, 32, 0), 0x4000, 0x40000));
+
+        // smaller ROMs that evenly fill a bank are mirrored
+        assert!(prg_rom_fits(&ines(0, 1, 0), 0x8000, 0x8000));
+        assert!(!prg_rom_fits(&ines(0, 3, 0), 0x8000, 0x10000));
+        assert!(!prg_rom_fits(&ines(0, 0, 0), 0x8000, 0x8000));
+
+        assert!(mapper(&ines(0, 2, 1)).supports_prg_rom_size(&ines(0, 2, 1)));
+        assert!(!mapper(&ines(0, 4, 1)).supports_prg_rom_size(&ines(0, 4, 1)));
+        assert!(!mapper(&ines(66, 16, 1)).supports_prg_rom_size(&ines(66, 16, 1)));
+    }
+
+    #[test]
+    fn uxrom() {
+        let meta = ines(2, 8, 0);
+        let mut m = mapper(&meta);
+
+        // the last bank is fixed at $C000
+        assert_eq!(prg(&*m, &meta, 0xC000), 7 * 0x4000);
+        assert_eq!(prg(&*m, &meta, 0x8000), 0);
+
+        m.map_cpu_write(&meta, 0x8000, 3);
+        assert_eq!(prg(&*m, &meta, 0x8001), 3 * 0x4000 + 1);
+        assert_eq!(prg(&*m, &meta, 0xFFFF), 8 * 0x4000 - 1);
+
+        // bank numbers wrap around the ROM size
+        m.map_cpu_write(&meta, 0x8000, 9);
+        assert_eq!(prg(&*m, &meta, 0x8000), 0x4000);
+    }
+
+    #[test]
+    fn cnrom() {
+        let meta = ines(3, 2, 4);
+        let mut m = mapper(&meta);
+
+        m.map_cpu_write(&meta, 0x8000, 2);
+        assert_eq!(chr(&*m, &meta, 0x0010), 2 * 0x2000 + 0x10);
+        assert_eq!(prg(&*m, &meta, 0xFFFF), 0x7FFF);
+
+        m.map_cpu_write(&meta, 0x8000, 5);
+        assert_eq!(chr(&*m, &meta, 0x0010), 0x2000 + 0x10);
+    }
+
+    #[test]
+    fn axrom() {
+        let meta = ines(7, 8, 0);
+        let mut m = mapper(&meta);
+
+        m.map_cpu_write(&meta, 0x8000, 0x13);
+        assert_eq!(prg(&*m, &meta, 0x8000), 3 * 0x8000);
+        assert_eq!(prg(&*m, &meta, 0xFFFF), 4 * 0x8000 - 1);
+        assert_eq!(m.mirroring(&meta), Mirroring::SingleScreenUpper);
+
+        m.map_cpu_write(&meta, 0x8000, 0x01);
+        assert_eq!(m.mirroring(&meta), Mirroring::SingleScreenLower);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn color_dreams_and_gxrom() {
+        // CCCC LLPP
+        let meta = ines(11, 8, 16);
+        let mut m = mapper(&meta);
+        m.map_cpu_write(&meta, 0x8000, 0x52);
+        assert_eq!(prg(&*m, &meta, 0x8000), 2 * 0x8000);
+        assert_eq!(chr(&*m, &meta, 0x0001), 5 * 0x2000 + 1);
+
+        // xxPP xxCC
+        let meta = ines(66, 4, 4);
+        let mut m = mapper(&meta);
+        m.map_cpu_write(&meta, 0x8000, 0x13);
+        assert_eq!(prg(&*m, &meta, 0x8000), 0x8000);
+        assert_eq!(chr(&*m, &meta, 0x0001), 3 * 0x2000 + 1);
+    }
+
+    #[test]
+    fn bnrom_and_nina001() {
+        // iNES files are told apart by their CHR size
+        let meta = ines(34, 8, 0);
+        let mut m = mapper(&meta);
+        m.map_cpu_write(&meta, 0x8000, 2);
+        assert_eq!(prg(&*m, &meta, 0x8000), 2 * 0x8000);
+        assert_eq!(m.map_cpu(&meta, 0x7FFD), MappedCpuAddress::None);
+
+        let meta = ines(34, 4, 2);
+        let mut m = mapper(&meta);
+        m.map_cpu_write(&meta, 0x7FFD, 1);
+        m.map_cpu_write(&meta, 0x7FFF, 3);
+        assert_eq!(prg(&*m, &meta, 0x8000), 0x8000);
+        assert_eq!(chr(&*m, &meta, 0x1001), 3 * 0x1000 + 1);
+
+        // NES 2.0 names the board, BNROM with 16KB of CHR ROM and NINA-001 with 8KB
+        let meta = nes20(34, 2, 4, 2);
+        let mut m = mapper(&meta);
+        m.map_cpu_write(&meta, 0x7FFD, 1);
+        assert_eq!(prg(&*m, &meta, 0x8000), 0);
+        assert_eq!(m.map_cpu(&meta, 0x6000), MappedCpuAddress::None);
+
+        let meta = nes20(34, 1, 4, 1);
+        let mut m = mapper(&meta);
+        m.map_cpu_write(&meta, 0x7FFD, 1);
+        assert_eq!(prg(&*m, &meta, 0x8000), 0x8000);
+        assert_eq!(m.map_cpu(&meta, 0x6000), MappedCpuAddress::PrgRam(0));
+    }
+}
@@ -0,0 +1,263 @@
+use crate::cartridge::{Header, Mirroring};
+use super::{Mapper, MappedCpuAddress, MappedPpuAddress, chr_address, prg_rom_fits};
+
+// MMC1 / SxROM, see https://wiki.nesdev.org/w/index.php/MMC1
+pub struct Mapper001 {
+    shift: u8,              // 5 bit serial shift register, the 1 marks when it is full
+    control: u8,            // $8000-$9FFF
+    chr_bank_0: u8,         // $A000-$BFFF
+    chr_bank_1: u8,         // $C000-$DFFF
+    prg_bank: u8,           // $E000-$FFFF
+    write_cooldown: u8,     // writes on consecutive cycles are ignored
+}
+
+impl Mapper001 {
+    pub fn new() -> Mapper001 {
+        Mapper001 {
+            shift: 0x10,
+            control: 0x0C, // PRG ROM mode 3 at power on
+            chr_bank_0: 0,
+            chr_bank_1: 0,
+            prg_bank: 0,
+            write_cooldown: 0,
+        }
+    }
+
+    fn write_register(&mut self, addr: u16, data: u8) {
+        match addr {
+            0x8000..=0x9FFF => self.control = data,
+            0xA000..=0xBFFF => self.chr_bank_0 = data,
+            0xC000..=0xDFFF => self.chr_bank_1 = data,
+            _ => self.prg_bank = data,
+        }
+    }
+
+    fn prg_ram_enabled(&self) -> bool {
+        self.prg_bank & 0x10 == 0
+    }
+}
+
+impl Mapper for Mapper001 {
+    fn map_cpu(&self, meta: &Header, addr: u16) -> MappedCpuAddress {
+        let banks = (meta.prg_rom_size / 0x4000).max(1);
+        let bank = (self.prg_bank & 0x0F) as usize;
+
+        let bank = match addr {
+            x if x >= 0x8000 => {
+                let upper = x >= 0xC000;
+
+                match (self.control >> 2) & 0x3 {
+                    // switch 32KB at $8000, ignoring the low bit of the bank number
+                    0 | 1 => (bank & !1) + upper as usize,
+                    // fix first bank at $8000 and switch 16KB bank at $C000
+                    2 => if upper { bank } else { 0 },
+                    // fix last bank at $C000 and switch 16KB bank at $8000
+                    _ => if upper { banks - 1 } else { bank },
+                }
+            }
+            x if x >= 0x6000 && self.prg_ram_enabled() => {
+                return MappedCpuAddress::PrgRam((addr - 0x6000) as usize);
+            }
+            _ => return MappedCpuAddress::None,
+        };
+
+        MappedCpuAddress::PrgRom((bank % banks) * 0x4000 + (addr & 0x3FFF) as usize)
+    }
+
+    fn map_cpu_write(&mut self, meta: &Header, addr: u16, data: u8) -> MappedCpuAddress {
+        if addr < 0x8000 {
+            return match self.map_cpu(meta, addr) {
+                MappedCpuAddress::PrgRam(addr) => MappedCpuAddress::PrgRam(addr),
+                _ => MappedCpuAddress::None,
+            };
+        }
+
+        // the serial port ignores all but the first write of consecutive write cycles
+        if self.write_cooldown > 0 {
+            return MappedCpuAddress::None;
+        }
+        self.write_cooldown = 2;
+
+        if data & 0x80 != 0 {
+            // reset shift register and lock PRG ROM mode 3
+            self.shift = 0x10;
+            self.control |= 0x0C;
+        } else {
+            let full = self.shift & 1 == 1;
+            self.shift = (self.shift >> 1) | ((data & 1) << 4);
+
+            // the fifth write copies the shift register into the register selected by the address
+            if full {
+                self.write_register(addr, self.shift);
+                self.shift = 0x10;
+            }
+        }
+
+        MappedCpuAddress::None
+    }
+
+    fn map_ppu(&self, meta: &Header, addr: u16) -> MappedPpuAddress {
+        if addr >= 0x2000 {
+            return MappedPpuAddress::None;
+        }
+
+        let offset = if self.control & 0x10 == 0 {
+            // switch 8KB at a time, ignoring the low bit of the bank number
+            (self.chr_bank_0 as usize & 0x1E) * 0x1000 + addr as usize
+        } else {
+            // switch two separate 4KB banks
+            let bank = if addr < 0x1000 { self.chr_bank_0 } else { self.chr_bank_1 };
+            bank as usize * 0x1000 + (addr & 0x0FFF) as usize
+        };
+
+        chr_address(meta, offset)
+    }
+
+    // 16KB banks, the 4 bit bank register reaches 256KB
+    fn supports_prg_rom_size(&self, meta: &Header) -> bool {
+        prg_rom_fits(meta, 0x4000, 0x40000)
+    }
+
+    fn mirroring(&self, _meta: &Header) -> Mirroring {
+        match self.control & 0x3 {
+            0 => Mirroring::SingleScreenLower,
+            1 => Mirroring::SingleScreenUpper,
+            2 => Mirroring::Vertical,
+            _ => Mirroring::Horizontal,
+        }
+    }
+
+    fn cpu_clock(&mut self) {
+        self.write_cooldown = self.write_cooldown.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartridge::mappers::tests::ines;
+
+    // shifts the low 5 bits of value into the register selected by addr, LSB first
+    fn write_serial(m: &mut Mapper001, meta: &Header, addr: u16, value: u8) {
+        for i in 0..5 {
+            m.map_cpu_write(meta, addr, (value >> i) & 1);
+            m.cpu_clock();
+            m.cpu_clock();
+        }
+    }
+
+    fn prg(m: &Mapper001, meta: &Header, addr: u16) -> usize {
+        match m.map_cpu(meta, addr) {
+            MappedCpuAddress::PrgRom(offset) => offset,
+            other => panic!("${:04X} is mapped to {:?}", addr, other),
+        }
+    }
+
+    #[test]
+    fn shift_register() {
+        let meta = ines(1, 8, 2);
+        let mut m = Mapper001::new();
+
+        write_serial(&mut m, &meta, 0xE000, 0x05);
+        assert_eq!(m.prg_bank, 0x05);
+        assert_eq!(m.shift, 0x10);
+
+        // the register is only written on the fifth write
+        for _ in 0..4 {
+            m.map_cpu_write(&meta, 0xA000, 1);
+            m.cpu_clock();
+            m.cpu_clock();
+        }
+        assert_eq!(m.chr_bank_0, 0);
+        m.map_cpu_write(&meta, 0xA000, 1);
+        assert_eq!(m.chr_bank_0, 0x1F);
+    }
+
+    #[test]
+    fn reset_bit() {
+        let meta = ines(1, 8, 2);
+        let mut m = Mapper001::new();
+        write_serial(&mut m, &meta, 0x8000, 0x00);
+        assert_eq!(m.control, 0x00);
+
+        // a write with bit 7 set drops the bits shifted in so far and locks PRG ROM mode 3
+        m.map_cpu_write(&meta, 0x8000, 1);
+        m.cpu_clock();
+        m.cpu_clock();
+        m.map_cpu_write(&meta, 0x8000, 0x80);
+        m.cpu_clock();
+        m.cpu_clock();
+        assert_eq!(m.shift, 0x10);
+        assert_eq!(m.control, 0x0C);
+
+        write_serial(&mut m, &meta, 0xE000, 0x03);
+        assert_eq!(m.prg_bank, 0x03);
+    }
+
+    #[test]
+    fn consecutive_writes_are_ignored() {
+        let meta = ines(1, 8, 2);
+        let mut m = Mapper001::new();
+
+        // read-modify-write instructions write twice on back to back cycles
+        m.map_cpu_write(&meta, 0xE000, 1);
+        m.map_cpu_write(&meta, 0xE000, 0);
+        m.cpu_clock();
+        m.cpu_clock();
+        for _ in 0..4 {
+            m.map_cpu_write(&meta, 0xE000, 0);
+            m.cpu_clock();
+            m.cpu_clock();
+        }
+        assert_eq!(m.prg_bank, 0x01);
+    }
+
+    #[test]
+    fn prg_banks() {
+        let meta = ines(1, 8, 2);
+        let mut m = Mapper001::new();
+
+        // mode 3 at power on, last bank fixed at $C000
+        assert_eq!(prg(&m, &meta, 0x8000), 0);
+        assert_eq!(prg(&m, &meta, 0xC000), 7 * 0x4000);
+
+        write_serial(&mut m, &meta, 0xE000, 0x03);
+        assert_eq!(prg(&m, &meta, 0x8123), 3 * 0x4000 + 0x123);
+        assert_eq!(prg(&m, &meta, 0xFFFF), 8 * 0x4000 - 1);
+
+        // mode 2, first bank fixed at $8000
+        write_serial(&mut m, &meta, 0x8000, 0x08);
+        assert_eq!(prg(&m, &meta, 0x8000), 0);
+        assert_eq!(prg(&m, &meta, 0xC000), 3 * 0x4000);
+
+        // 32KB mode ignores the low bit of the bank number
+        write_serial(&mut m, &meta, 0x8000, 0x00);
+        assert_eq!(prg(&m, &meta, 0x8000), 2 * 0x4000);
+        assert_eq!(prg(&m, &meta, 0xC000), 3 * 0x4000);
+
+        // bit 4 of the PRG bank disables PRG RAM
+        assert_eq!(m.map_cpu(&meta, 0x6000), MappedCpuAddress::PrgRam(0));
+        write_serial(&mut m, &meta, 0xE000, 0x10);
+        assert_eq!(m.map_cpu(&meta, 0x6000), MappedCpuAddress::None);
+    }
+
+    #[test]
+    fn chr_banks_and_mirroring() {
+        let meta = ines(1, 8, 4);
+        let mut m = Mapper001::new();
+
+        // 8KB mode ignores the low bit of the bank number
+        write_serial(&mut m, &meta, 0xA000, 0x03);
+        assert_eq!(m.map_ppu(&meta, 0x1001), MappedPpuAddress::ChrRom(0x2000 + 0x1001));
+
+        // 4KB mode, vertical mirroring
+        write_serial(&mut m, &meta, 0x8000, 0x12);
+        write_serial(&mut m, &meta, 0xC000, 0x05);
+        assert_eq!(m.map_ppu(&meta, 0x0001), MappedPpuAddress::ChrRom(3 * 0x1000 + 1));
+        assert_eq!(m.map_ppu(&meta, 0x1001), MappedPpuAddress::ChrRom(5 * 0x1000 + 1));
+        assert_eq!(m.mirroring(&meta), Mirroring::Vertical);
+
+        write_serial(&mut m, &meta, 0x8000, 0x01);
+        assert_eq!(m.mirroring(&meta), Mirroring::SingleScreenUpper);
+    }
+}
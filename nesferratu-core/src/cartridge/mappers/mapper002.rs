@@ -0,0 +1,53 @@
+use crate::cartridge::Header;
+use super::{Mapper, MappedCpuAddress, MappedPpuAddress, chr_address, prg_rom_fits};
+
+// UxROM, see https://wiki.nesdev.org/w/index.php/UxROM
+pub struct Mapper002 {
+    bank: u8,   // $8000-$FFFF, 16KB PRG ROM bank at $8000
+}
+
+impl Mapper002 {
+    pub fn new() -> Mapper002 {
+        Mapper002 {
+            bank: 0,
+        }
+    }
+}
+
+impl Mapper for Mapper002 {
+    fn map_cpu(&self, meta: &Header, addr: u16) -> MappedCpuAddress {
+        let banks = (meta.prg_rom_size / 0x4000).max(1);
+
+        let bank = match addr {
+            0x8000..=0xBFFF => self.bank as usize,
+            0xC000..=0xFFFF => banks - 1, // last bank is fixed at $C000
+            _ => return MappedCpuAddress::None,
+        };
+
+        MappedCpuAddress::PrgRom((bank % banks) * 0x4000 + (addr & 0x3FFF) as usize)
+    }
+
+    fn map_cpu_write(&mut self, _meta: &Header, addr: u16, data: u8) -> MappedCpuAddress {
+        if addr >= 0x8000 {
+            self.bank = data;
+        }
+
+        MappedCpuAddress::None
+    }
+
+    fn map_ppu(&self, meta: &Header, addr: u16) -> MappedPpuAddress {
+        match addr {
+            x if x < 0x2000 => chr_address(meta, addr as usize),
+            _ => MappedPpuAddress::None,
+        }
+    }
+
+    // 16KB banks, the 8 bit bank register reaches 4MB
+    fn supports_prg_rom_size(&self, meta: &Header) -> bool {
+        prg_rom_fits(meta, 0x4000, 0x400000)
+    }
+
+    fn bus_conflicts(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,49 @@
+use crate::cartridge::Header;
+use super::{Mapper, MappedCpuAddress, MappedPpuAddress, chr_address, prg_rom_fits};
+
+// CNROM, see https://wiki.nesdev.org/w/index.php/CNROM
+pub struct Mapper003 {
+    bank: u8,   // $8000-$FFFF, 8KB CHR ROM bank
+}
+
+impl Mapper003 {
+    pub fn new() -> Mapper003 {
+        Mapper003 {
+            bank: 0,
+        }
+    }
+}
+
+impl Mapper for Mapper003 {
+    fn map_cpu(&self, meta: &Header, addr: u16) -> MappedCpuAddress {
+        match addr {
+            // 16KB PRG ROM is mirrored into $C000-$FFFF
+            x if x >= 0x8000 => MappedCpuAddress::PrgRom((addr - 0x8000) as usize % meta.prg_rom_size.max(1)),
+            _ => MappedCpuAddress::None,
+        }
+    }
+
+    fn map_cpu_write(&mut self, _meta: &Header, addr: u16, data: u8) -> MappedCpuAddress {
+        if addr >= 0x8000 {
+            self.bank = data;
+        }
+
+        MappedCpuAddress::None
+    }
+
+    fn map_ppu(&self, meta: &Header, addr: u16) -> MappedPpuAddress {
+        match addr {
+            x if x < 0x2000 => chr_address(meta, self.bank as usize * 0x2000 + addr as usize),
+            _ => MappedPpuAddress::None,
+        }
+    }
+
+    // fixed 16KB or 32KB, smaller ROMs are mirrored
+    fn supports_prg_rom_size(&self, meta: &Header) -> bool {
+        prg_rom_fits(meta, 0x8000, 0x8000)
+    }
+
+    fn bus_conflicts(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,362 @@
+use crate::cartridge::{Header, Mirroring};
+use super::{Mapper, MappedCpuAddress, MappedPpuAddress, chr_address, prg_rom_fits};
+
+// MMC3 / TxROM, see https://wiki.nesdev.org/w/index.php/MMC3
+pub struct Mapper004 {
+    bank_select: u8,        // $8000
+    banks: [u8; 8],         // $8001, R0-R7
+    mirroring: u8,          // $A000
+    prg_ram_protect: u8,    // $A001
+
+    // scanline counter
+    irq_latch: u8,          // $C000
+    irq_counter: u8,
+    irq_reload: bool,       // $C001
+    irq_enabled: bool,      // $E000/$E001
+    irq_pending: bool,
+
+    // A12 edge detection, rises are only counted after A12 has been low for a few CPU cycles
+    a12: bool,
+    a12_low_cycles: u8,
+}
+
+impl Mapper004 {
+    pub fn new() -> Mapper004 {
+        Mapper004 {
+            bank_select: 0,
+            banks: [0, 2, 4, 5, 6, 7, 0, 1],
+            mirroring: 0,
+            prg_ram_protect: 0,
+
+            irq_latch: 0,
+            irq_counter: 0,
+            irq_reload: false,
+            irq_enabled: false,
+            irq_pending: false,
+
+            a12: false,
+            a12_low_cycles: 0,
+        }
+    }
+
+    fn clock_counter(&mut self) {
+        if self.irq_counter == 0 || self.irq_reload {
+            self.irq_counter = self.irq_latch;
+            self.irq_reload = false;
+        } else {
+            self.irq_counter -= 1;
+        }
+
+        if self.irq_counter == 0 && self.irq_enabled {
+            self.irq_pending = true;
+        }
+    }
+
+    fn prg_ram_enabled(&self) -> bool {
+        self.prg_ram_protect & 0x80 != 0
+    }
+
+    fn prg_ram_writable(&self) -> bool {
+        self.prg_ram_protect & 0x40 == 0
+    }
+}
+
+impl Mapper for Mapper004 {
+    fn map_cpu(&self, meta: &Header, addr: u16) -> MappedCpuAddress {
+        let banks = (meta.prg_rom_size / 0x2000).max(2);
+        let second_last = banks - 2;
+        let prg_mode = self.bank_select & 0x40 != 0;
+
+        let bank = match addr {
+            0x8000..=0x9FFF => if prg_mode { second_last } else { self.banks[6] as usize },
+            0xA000..=0xBFFF => self.banks[7] as usize,
+            0xC000..=0xDFFF => if prg_mode { self.banks[6] as usize } else { second_last },
+            0xE000..=0xFFFF => banks - 1,
+            0x6000..=0x7FFF if self.prg_ram_enabled() => {
+                return MappedCpuAddress::PrgRam((addr - 0x6000) as usize);
+            }
+            _ => return MappedCpuAddress::None,
+        };
+
+        MappedCpuAddress::PrgRom((bank % banks) * 0x2000 + (addr & 0x1FFF) as usize)
+    }
+
+    fn map_cpu_write(&mut self, meta: &Header, addr: u16, data: u8) -> MappedCpuAddress {
+        let even = addr & 1 == 0;
+
+        match addr {
+            0x6000..=0x7FFF if self.prg_ram_writable() => {
+                return self.map_cpu(meta, addr);
+            }
+            0x8000..=0x9FFF => {
+                if even {
+                    self.bank_select = data;
+                } else {
+                    self.banks[(self.bank_select & 0x7) as usize] = data;
+                }
+            }
+            0xA000..=0xBFFF => {
+                if even {
+                    self.mirroring = data & 1;
+                } else {
+                    self.prg_ram_protect = data;
+                }
+            }
+            0xC000..=0xDFFF => {
+                if even {
+                    self.irq_latch = data;
+                } else {
+                    self.irq_counter = 0;
+                    self.irq_reload = true;
+                }
+            }
+            0xE000..=0xFFFF => {
+                if even {
+                    self.irq_enabled = false;
+                    self.irq_pending = false;
+                } else {
+                    self.irq_enabled = true;
+                }
+            }
+            _ => {}
+        }
+
+        MappedCpuAddress::None
+    }
+
+    fn map_ppu(&self, meta: &Header, addr: u16) -> MappedPpuAddress {
+        if addr >= 0x2000 {
+            return MappedPpuAddress::None;
+        }
+
+        // CHR A12 inversion swaps the 2KB and 1KB halves
+        let inverted = if self.bank_select & 0x80 != 0 { addr ^ 0x1000 } else { addr };
+
+        let offset = match inverted {
+            0x0000..=0x07FF => (self.banks[0] as usize & 0xFE) * 0x400 + (inverted & 0x07FF) as usize,
+            0x0800..=0x0FFF => (self.banks[1] as usize & 0xFE) * 0x400 + (inverted & 0x07FF) as usize,
+            _ => {
+                let bank = self.banks[2 + ((inverted - 0x1000) / 0x400) as usize] as usize;
+                bank * 0x400 + (inverted & 0x03FF) as usize
+            }
+        };
+
+        chr_address(meta, offset)
+    }
+
+    // 8KB banks, the 6 bank bits reach 512KB
+    fn supports_prg_rom_size(&self, meta: &Header) -> bool {
+        prg_rom_fits(meta, 0x2000, 0x80000)
+    }
+
+    fn ppu_address(&mut self, addr: u16) {
+        let a12 = addr & 0x1000 != 0;
+
+        if a12 && !self.a12 && self.a12_low_cycles >= 3 {
+            self.clock_counter();
+        }
+
+        if a12 {
+            self.a12_low_cycles = 0;
+        }
+
+        self.a12 = a12;
+    }
+
+    fn mirroring(&self, meta: &Header) -> Mirroring {
+        if meta.mirroring == Mirroring::FourScreen {
+            Mirroring::FourScreen
+        } else if self.mirroring == 0 {
+            Mirroring::Vertical
+        } else {
+            Mirroring::Horizontal
+        }
+    }
+
+    fn cpu_clock(&mut self) {
+        if !self.a12 {
+            self.a12_low_cycles = self.a12_low_cycles.saturating_add(1);
+        }
+    }
+
+    fn irq(&self) -> bool {
+        self.irq_pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartridge::mappers::tests::ines;
+
+    fn write(m: &mut Mapper004, meta: &Header, addr: u16, data: u8) -> MappedCpuAddress {
+        m.map_cpu_write(meta, addr, data)
+    }
+
+    // selects register R0-R7 through $8000 and writes it through $8001
+    fn write_bank(m: &mut Mapper004, meta: &Header, mode: u8, register: u8, data: u8) {
+        write(m, meta, 0x8000, mode | register);
+        write(m, meta, 0x8001, data);
+    }
+
+    // one rise of PPU A12 after it has been low long enough to be counted
+    fn scanline(m: &mut Mapper004) {
+        m.ppu_address(0x0000);
+        for _ in 0..3 {
+            m.cpu_clock();
+        }
+        m.ppu_address(0x1000);
+    }
+
+    fn prg(m: &Mapper004, meta: &Header, addr: u16) -> usize {
+        match m.map_cpu(meta, addr) {
+            MappedCpuAddress::PrgRom(offset) => offset,
+            other => panic!("${:04X} is mapped to {:?}", addr, other),
+        }
+    }
+
+    fn chr(m: &Mapper004, meta: &Header, addr: u16) -> usize {
+        match m.map_ppu(meta, addr) {
+            MappedPpuAddress::ChrRom(offset) => offset,
+            other => panic!("${:04X} is mapped to {:?}", addr, other),
+        }
+    }
+
+    #[test]
+    fn prg_banks() {
+        // 128KB, 16 banks of 8KB
+        let meta = ines(4, 8, 16);
+        let mut m = Mapper004::new();
+        write_bank(&mut m, &meta, 0x00, 6, 3);
+        write_bank(&mut m, &meta, 0x00, 7, 5);
+
+        // mode 0, second last bank fixed at $C000
+        assert_eq!(prg(&m, &meta, 0x8001), 3 * 0x2000 + 1);
+        assert_eq!(prg(&m, &meta, 0xA000), 5 * 0x2000);
+        assert_eq!(prg(&m, &meta, 0xC000), 14 * 0x2000);
+        assert_eq!(prg(&m, &meta, 0xFFFF), 16 * 0x2000 - 1);
+
+        // mode 1 swaps $8000 and $C000
+        write(&mut m, &meta, 0x8000, 0x40);
+        assert_eq!(prg(&m, &meta, 0x8000), 14 * 0x2000);
+        assert_eq!(prg(&m, &meta, 0xA000), 5 * 0x2000);
+        assert_eq!(prg(&m, &meta, 0xC000), 3 * 0x2000);
+        assert_eq!(prg(&m, &meta, 0xE000), 15 * 0x2000);
+
+        // bank numbers wrap around the ROM size
+        write_bank(&mut m, &meta, 0x40, 7, 0x15);
+        assert_eq!(prg(&m, &meta, 0xA000), 5 * 0x2000);
+    }
+
+    #[test]
+    fn chr_banks() {
+        let meta = ines(4, 8, 16);
+        let mut m = Mapper004::new();
+
+        // R0 and R1 select 2KB banks and ignore the low bit
+        write_bank(&mut m, &meta, 0x00, 0, 5);
+        write_bank(&mut m, &meta, 0x00, 1, 8);
+        for (i, bank) in [20, 21, 22, 23].iter().enumerate() {
+            write_bank(&mut m, &meta, 0x00, 2 + i as u8, *bank);
+        }
+
+        assert_eq!(chr(&m, &meta, 0x0000), 4 * 0x400);
+        assert_eq!(chr(&m, &meta, 0x0401), 4 * 0x400 + 0x401);
+        assert_eq!(chr(&m, &meta, 0x0800), 8 * 0x400);
+        assert_eq!(chr(&m, &meta, 0x1000), 20 * 0x400);
+        assert_eq!(chr(&m, &meta, 0x1C01), 23 * 0x400 + 1);
+
+        // CHR A12 inversion puts the 1KB banks at $0000 and the 2KB banks at $1000
+        write(&mut m, &meta, 0x8000, 0x80);
+        assert_eq!(chr(&m, &meta, 0x0000), 20 * 0x400);
+        assert_eq!(chr(&m, &meta, 0x0C01), 23 * 0x400 + 1);
+        assert_eq!(chr(&m, &meta, 0x1000), 4 * 0x400);
+        assert_eq!(chr(&m, &meta, 0x1C01), 8 * 0x400 + 0x401);
+    }
+
+    #[test]
+    fn mirroring_and_prg_ram() {
+        let meta = ines(4, 8, 16);
+        let mut m = Mapper004::new();
+
+        assert_eq!(m.mirroring(&meta), Mirroring::Vertical);
+        write(&mut m, &meta, 0xA000, 1);
+        assert_eq!(m.mirroring(&meta), Mirroring::Horizontal);
+
+        // PRG RAM is disabled until $A001 enables it
+        assert_eq!(m.map_cpu(&meta, 0x6000), MappedCpuAddress::None);
+        write(&mut m, &meta, 0xA001, 0x80);
+        assert_eq!(m.map_cpu(&meta, 0x6001), MappedCpuAddress::PrgRam(1));
+        assert_eq!(write(&mut m, &meta, 0x7FFF, 0), MappedCpuAddress::PrgRam(0x1FFF));
+
+        // write protection keeps it readable
+        write(&mut m, &meta, 0xA001, 0xC0);
+        assert_eq!(write(&mut m, &meta, 0x6000, 0), MappedCpuAddress::None);
+        assert_eq!(m.map_cpu(&meta, 0x6000), MappedCpuAddress::PrgRam(0));
+    }
+
+    #[test]
+    fn irq_counter() {
+        let meta = ines(4, 8, 16);
+        let mut m = Mapper004::new();
+        write(&mut m, &meta, 0xC000, 2);
+        write(&mut m, &meta, 0xC001, 0);
+        write(&mut m, &meta, 0xE001, 0);
+
+        // the first rise reloads the counter, the IRQ fires when it reaches 0
+        scanline(&mut m);
+        assert_eq!(m.irq_counter, 2);
+        scanline(&mut m);
+        assert!(!m.irq());
+        scanline(&mut m);
+        assert!(m.irq());
+
+        // writing $E000 acknowledges and disables the IRQ, the counter keeps running
+        write(&mut m, &meta, 0xE000, 0);
+        assert!(!m.irq());
+        for _ in 0..3 {
+            scanline(&mut m);
+        }
+        assert_eq!(m.irq_counter, 0);
+        assert!(!m.irq());
+
+        // $C001 reloads the counter from the latch on the next rise
+        write(&mut m, &meta, 0xE001, 0);
+        scanline(&mut m);
+        write(&mut m, &meta, 0xC000, 5);
+        write(&mut m, &meta, 0xC001, 0);
+        scanline(&mut m);
+        assert_eq!(m.irq_counter, 5);
+        assert!(!m.irq());
+
+        // a latch of 0 fires on every rise
+        write(&mut m, &meta, 0xC000, 0);
+        write(&mut m, &meta, 0xC001, 0);
+        scanline(&mut m);
+        assert!(m.irq());
+    }
+
+    #[test]
+    fn a12_filter() {
+        let meta = ines(4, 8, 16);
+        let mut m = Mapper004::new();
+        write(&mut m, &meta, 0xC000, 10);
+        scanline(&mut m);
+        assert_eq!(m.irq_counter, 10);
+
+        // rises after A12 was low for less than 3 CPU cycles are not counted
+        m.ppu_address(0x0000);
+        m.cpu_clock();
+        m.cpu_clock();
+        m.ppu_address(0x1000);
+        assert_eq!(m.irq_counter, 10);
+
+        // neither is A12 staying high
+        m.cpu_clock();
+        m.ppu_address(0x1400);
+        assert_eq!(m.irq_counter, 10);
+
+        scanline(&mut m);
+        assert_eq!(m.irq_counter, 9);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::cartridge::{Header, Mirroring};
+use super::{Mapper, MappedCpuAddress, MappedPpuAddress, chr_address, prg_32k_address, prg_rom_fits};
+
+// AxROM, see https://wiki.nesdev.org/w/index.php/AxROM
+pub struct Mapper007 {
+    bank: u8,   // $8000-$FFFF, 32KB PRG ROM bank and nametable select
+}
+
+impl Mapper007 {
+    pub fn new() -> Mapper007 {
+        Mapper007 {
+            bank: 0,
+        }
+    }
+}
+
+impl Mapper for Mapper007 {
+    fn map_cpu(&self, meta: &Header, addr: u16) -> MappedCpuAddress {
+        match addr {
+            x if x >= 0x8000 => prg_32k_address(meta, (self.bank & 0x07) as usize, addr),
+            _ => MappedCpuAddress::None,
+        }
+    }
+
+    fn map_cpu_write(&mut self, _meta: &Header, addr: u16, data: u8) -> MappedCpuAddress {
+        if addr >= 0x8000 {
+            self.bank = data;
+        }
+
+        MappedCpuAddress::None
+    }
+
+    fn map_ppu(&self, meta: &Header, addr: u16) -> MappedPpuAddress {
+        match addr {
+            x if x < 0x2000 => chr_address(meta, addr as usize),
+            _ => MappedPpuAddress::None,
+        }
+    }
+
+    // 32KB banks, the 3 bit bank number reaches 256KB
+    fn supports_prg_rom_size(&self, meta: &Header) -> bool {
+        prg_rom_fits(meta, 0x8000, 0x40000)
+    }
+
+    fn mirroring(&self, _meta: &Header) -> Mirroring {
+        if self.bank & 0x10 == 0 {
+            Mirroring::SingleScreenLower
+        } else {
+            Mirroring::SingleScreenUpper
+        }
+    }
+}
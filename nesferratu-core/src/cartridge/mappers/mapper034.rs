@@ -0,0 +1,85 @@
+use crate::cartridge::Header;
+use super::{Mapper, MappedCpuAddress, MappedPpuAddress, chr_address, prg_32k_address, prg_rom_fits};
+
+// BNROM and NINA-001, see https://wiki.nesdev.org/w/index.php/INES_Mapper_034
+// NES 2.0 tells the boards apart with submapper 1 (NINA-001) and 2 (BNROM), for iNES files
+// we guess from the CHR size, BNROM boards use CHR RAM and NINA-001 boards have more than 8KB of CHR ROM
+pub struct Mapper034 {
+    prg_bank: u8,       // $8000-$FFFF (BNROM) or $7FFD (NINA-001), 32KB PRG ROM bank
+    chr_banks: [u8; 2], // $7FFE/$7FFF (NINA-001), 4KB CHR ROM banks
+}
+
+impl Mapper034 {
+    pub fn new() -> Mapper034 {
+        Mapper034 {
+            prg_bank: 0,
+            chr_banks: [0, 1],
+        }
+    }
+
+    fn is_nina001(meta: &Header) -> bool {
+        match meta.submapper {
+            1 if meta.is_nes20 => true,
+            2 if meta.is_nes20 => false,
+            _ => meta.chr_rom_size > 0x2000,
+        }
+    }
+}
+
+impl Mapper for Mapper034 {
+    fn map_cpu(&self, meta: &Header, addr: u16) -> MappedCpuAddress {
+        match addr {
+            x if x >= 0x8000 => prg_32k_address(meta, self.prg_bank as usize, addr),
+            x if x >= 0x6000 && Self::is_nina001(meta) => MappedCpuAddress::PrgRam((addr - 0x6000) as usize),
+            _ => MappedCpuAddress::None,
+        }
+    }
+
+    fn map_cpu_write(&mut self, meta: &Header, addr: u16, data: u8) -> MappedCpuAddress {
+        if !Self::is_nina001(meta) {
+            if addr >= 0x8000 {
+                self.prg_bank = data;
+            }
+            return MappedCpuAddress::None;
+        }
+
+        // NINA-001 registers sit on top of the PRG RAM, writes go to both
+        match addr {
+            0x7FFD => self.prg_bank = data & 0x01,
+            0x7FFE => self.chr_banks[0] = data & 0x0F,
+            0x7FFF => self.chr_banks[1] = data & 0x0F,
+            _ => {}
+        }
+
+        match self.map_cpu(meta, addr) {
+            MappedCpuAddress::PrgRam(addr) => MappedCpuAddress::PrgRam(addr),
+            _ => MappedCpuAddress::None,
+        }
+    }
+
+    fn map_ppu(&self, meta: &Header, addr: u16) -> MappedPpuAddress {
+        if addr >= 0x2000 {
+            return MappedPpuAddress::None;
+        }
+
+        if Self::is_nina001(meta) {
+            let bank = self.chr_banks[(addr >> 12) as usize];
+            chr_address(meta, bank as usize * 0x1000 + (addr & 0x0FFF) as usize)
+        } else {
+            chr_address(meta, addr as usize)
+        }
+    }
+
+    // 32KB banks, NINA-001 has a single bank bit and BNROM uses the whole byte
+    fn supports_prg_rom_size(&self, meta: &Header) -> bool {
+        if Self::is_nina001(meta) {
+            prg_rom_fits(meta, 0x8000, 0x10000)
+        } else {
+            prg_rom_fits(meta, 0x8000, 0x800000)
+        }
+    }
+
+    fn bus_conflicts(&self) -> bool {
+        true
+    }
+}
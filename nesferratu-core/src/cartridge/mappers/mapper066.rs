@@ -0,0 +1,48 @@
+use crate::cartridge::Header;
+use super::{Mapper, MappedCpuAddress, MappedPpuAddress, chr_address, prg_32k_address, prg_rom_fits};
+
+// GxROM, see https://wiki.nesdev.org/w/index.php/GxROM
+pub struct Mapper066 {
+    bank: u8,   // $8000-$FFFF, xxPP xxCC: 32KB PRG ROM bank, 8KB CHR ROM bank
+}
+
+impl Mapper066 {
+    pub fn new() -> Mapper066 {
+        Mapper066 {
+            bank: 0,
+        }
+    }
+}
+
+impl Mapper for Mapper066 {
+    fn map_cpu(&self, meta: &Header, addr: u16) -> MappedCpuAddress {
+        match addr {
+            x if x >= 0x8000 => prg_32k_address(meta, ((self.bank >> 4) & 0x03) as usize, addr),
+            _ => MappedCpuAddress::None,
+        }
+    }
+
+    fn map_cpu_write(&mut self, _meta: &Header, addr: u16, data: u8) -> MappedCpuAddress {
+        if addr >= 0x8000 {
+            self.bank = data;
+        }
+
+        MappedCpuAddress::None
+    }
+
+    fn map_ppu(&self, meta: &Header, addr: u16) -> MappedPpuAddress {
+        match addr {
+            x if x < 0x2000 => chr_address(meta, (self.bank & 0x03) as usize * 0x2000 + addr as usize),
+            _ => MappedPpuAddress::None,
+        }
+    }
+
+    // 32KB banks, the 2 bit bank number reaches 128KB
+    fn supports_prg_rom_size(&self, meta: &Header) -> bool {
+        prg_rom_fits(meta, 0x8000, 0x20000)
+    }
+
+    fn bus_conflicts(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,153 @@
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ButtonState {
+    pub a: bool,
+    pub b: bool,
+    pub select: bool,
+    pub start: bool,
+    pub up: bool,
+    pub down: bool,
+    pub left: bool,
+    pub right: bool,
+}
+
+impl ButtonState {
+    // buttons in the order they are shifted out by the controller, A first
+    pub fn to_byte(&self) -> u8 {
+        (self.a as u8)
+            | (self.b as u8) << 1
+            | (self.select as u8) << 2
+            | (self.start as u8) << 3
+            | (self.up as u8) << 4
+            | (self.down as u8) << 5
+            | (self.left as u8) << 6
+            | (self.right as u8) << 7
+    }
+}
+
+// controller ports on the front of the console
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Port {
+    One = 0,    // read through $4016
+    Two = 1,    // read through $4017
+}
+
+// standard controller, a 4021 8-bit parallel-in serial-out shift register
+#[derive(Default)]
+pub struct Controller {
+    buttons: ButtonState,
+    shift: u8,
+    reads: u8,
+    strobe: bool,
+}
+
+impl Controller {
+    pub fn new() -> Controller {
+        Controller::default()
+    }
+
+    pub fn set_buttons(&mut self, buttons: ButtonState) {
+        self.buttons = buttons;
+
+        if self.strobe {
+            self.latch();
+        }
+    }
+
+    pub fn buttons(&self) -> ButtonState {
+        self.buttons
+    }
+
+    // bit 0 of $4016 writes, the shift register is continuously reloaded while strobe is high
+    pub fn write_strobe(&mut self, strobe: bool) {
+        self.strobe = strobe;
+
+        if strobe {
+            self.latch();
+        }
+    }
+
+    // returns the next bit in D0
+    pub fn read(&mut self) -> u8 {
+        if self.strobe {
+            // while strobe is high the state of A is returned over and over
+            return self.buttons.a as u8;
+        }
+
+        // after all 8 buttons have been read official controllers return 1
+        if self.reads >= 8 {
+            return 1;
+        }
+
+        let bit = self.shift & 1;
+        self.shift >>= 1;
+        self.reads += 1;
+        bit
+    }
+
+    fn latch(&mut self) {
+        self.shift = self.buttons.to_byte();
+        self.reads = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_byte(c: &mut Controller) -> u8 {
+        (0..8).fold(0, |byte, i| byte | c.read() << i)
+    }
+
+    #[test]
+    fn button_order() {
+        let buttons = ButtonState { a: true, start: true, left: true, ..Default::default() };
+        assert_eq!(buttons.to_byte(), 0b0100_1001);
+    }
+
+    #[test]
+    fn shift_after_strobe() {
+        let mut c = Controller::new();
+        c.set_buttons(ButtonState { a: true, select: true, right: true, ..Default::default() });
+
+        c.write_strobe(true);
+        c.write_strobe(false);
+        assert_eq!(read_byte(&mut c), 0b1000_0101);
+
+        // official controllers return 1 once all buttons have been read
+        assert_eq!(c.read(), 1);
+        assert_eq!(c.read(), 1);
+    }
+
+    #[test]
+    fn strobe_high_returns_a() {
+        let mut c = Controller::new();
+        c.set_buttons(ButtonState { a: true, b: true, ..Default::default() });
+        c.write_strobe(true);
+
+        for _ in 0..10 {
+            assert_eq!(c.read(), 1);
+        }
+
+        // the shift register is reloaded while strobe is high
+        c.set_buttons(ButtonState { b: true, ..Default::default() });
+        assert_eq!(c.read(), 0);
+        c.write_strobe(false);
+        assert_eq!(read_byte(&mut c), 0b0000_0010);
+    }
+
+    #[test]
+    fn buttons_are_latched() {
+        let mut c = Controller::new();
+        c.set_buttons(ButtonState { a: true, ..Default::default() });
+        c.write_strobe(true);
+        c.write_strobe(false);
+
+        // changes after the strobe only show up after the next one
+        c.set_buttons(ButtonState { b: true, ..Default::default() });
+        assert_eq!(read_byte(&mut c), 0b0000_0001);
+
+        c.write_strobe(true);
+        c.write_strobe(false);
+        assert_eq!(read_byte(&mut c), 0b0000_0010);
+    }
+}
@@ -9,7 +9,9 @@ use crate::BusMessage;
 use crate::debugger::CpuDebugger;
 
 pub trait CPU {
-    fn clock(&mut self, data: Option<u8>) -> BusMessage;
+    fn clock(&mut self, data: Option<u8>) -> Result<BusMessage, CpuError>;
+
+    fn stall(&mut self);
 
     fn irq(&mut self);
 
@@ -38,6 +40,33 @@ enum CpuInterpreterState {
     Halt,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CpuError {
+    IllegalOpcode { opcode: u8, pc: u16 },
+    MissingBusData { pc: u16, cycle: u8 },      // the bus didn't answer a read while fetching the instruction
+    FetchOverrun { opcode: u8, pc: u16 },       // instructions are at most 3 bytes long
+    AddressingOverrun { opcode: u8, cycle: u8 },
+    OperandMismatch { opcode: u8 },             // the operation can't handle the operand of its addressing mode
+    InvalidState(&'static str),                 // broken invariant of the interpreter
+    Jammed { opcode: u8, pc: u16 },             // KIL, stops the CPU until the next reset
+}
+
+impl Display for CpuError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CpuError::IllegalOpcode { opcode, pc } => write!(f, "Illegal opcode 0x{:02X} at 0x{:04X}", opcode, pc),
+            CpuError::MissingBusData { pc, cycle } => write!(f, "No data on the bus in fetch cycle {} at 0x{:04X}", cycle, pc),
+            CpuError::FetchOverrun { opcode, pc } => write!(f, "Fetch of opcode 0x{:02X} at 0x{:04X} took longer than 3 cycles", opcode, pc),
+            CpuError::AddressingOverrun { opcode, cycle } => write!(f, "Addressing of opcode 0x{:02X} can't continue in cycle {}", opcode, cycle),
+            CpuError::OperandMismatch { opcode } => write!(f, "Opcode 0x{:02X} got an operand its operation can't handle", opcode),
+            CpuError::InvalidState(msg) => write!(f, "Invalid interpreter state: {}", msg),
+            CpuError::Jammed { opcode, pc } => write!(f, "CPU jammed by opcode 0x{:02X} at 0x{:04X}", opcode, pc),
+        }
+    }
+}
+
+impl std::error::Error for CpuError {}
+
 #[derive(Debug, PartialEq)]
 pub enum Interrupt {
     None,
@@ -56,7 +85,8 @@ pub struct CpuState {
     o2: u8,             // 3rd byte of instruction
     addr: u16,          // scratch pad for addressing modes
     data: u8,           // data from bus
-    extra_cycle: bool   // flag to add one cycle to the instructions cycle length during the next cycle
+    extra_cycle: bool,  // flag to add one cycle to the instructions cycle length during the next cycle
+    halt: bool,         // set by KIL, stops the CPU until the next reset
 }
 
 #[derive(Default, Debug)]
@@ -118,6 +148,7 @@ pub struct EmulationState {
     pub additional_cycles: u8,
     pub instruction_done: bool,
     pub interrupt_request: Interrupt,
+    pub jam: Option<CpuError>,  // the error that stopped the CPU, cleared by a reset
 }
 
 impl Default for EmulationState {
@@ -128,6 +159,7 @@ impl Default for EmulationState {
             additional_cycles: 0,
             instruction_done: true,
             interrupt_request: Interrupt::None,
+            jam: None,
         }
     }
 }
@@ -162,66 +194,58 @@ impl CpuInterpreter {
         }
     }
 
-    fn print_debug(&self) {
-        println!("cycle: {}, op_cycle: {}, state: {:?}", self.emu_state.total_cycles, self.emu_state.op_cycle, self.exec_state);
-        if let Some(i) = self.instruction.as_ref() {
-            println!("op: {}, addr: {}, bytes: {}, cycles: {}", i.mnemonic, i.addressing, i.bytes, i.cycles);
-        }
-        println!("{:02X?}", self.cpu_state);
-        println!("Flags: NV-BDIZC");
-        println!("       {:08b}", self.cpu_state.regs.status);
+    // stops the CPU until the next reset
+    fn jam(&mut self, error: CpuError) {
+        self.exec_state = CpuInterpreterState::Halt;
+        self.emu_state.jam = Some(error);
     }
-}
 
-impl CPU for CpuInterpreter {
-
-    fn clock(&mut self, data: Option<u8>) -> BusMessage {
+    fn run_state_machine(&mut self, data: Option<u8>) -> Result<BusMessage, CpuError> {
 
         use CpuInterpreterState::*;
         use BusMessage::*;
 
-        self.emu_state.instruction_done = false;
-        self.emu_state.total_cycles += 1;
-        self.emu_state.op_cycle += 1;
-
-        if let Some(data) = data {
-            self.cpu_state.data = data;
-        }
-
-        // increase the cycle lenght of the current instruction if the current instruction requires it
-        if self.cpu_state.extra_cycle {
-            self.emu_state.additional_cycles += 1;
-            self.cpu_state.extra_cycle = false;
-        }
-
         // CPU state machine
 
         loop {
             match self.exec_state {
                 CpuInterpreterState::Fetch => {
-                    match self.emu_state.op_cycle {
+                    let pc = self.cpu_state.regs.pc;
+                    let cycle = self.emu_state.op_cycle;
+                    let data = data.ok_or(CpuError::MissingBusData{pc, cycle});
+
+                    match cycle {
                         1 => {
-                            self.cpu_state.op = data.expect("Bus data can't be empty in fetch cycle 1");
+                            self.cpu_state.op = data?;
                             self.instruction = Some(
                                 Opcode::from_u8(self.cpu_state.op)
-                                    .expect("Illegal Opcode")
+                                    .ok_or(CpuError::IllegalOpcode{opcode: self.cpu_state.op, pc})?
                                     .to_instruction()
                             );
-                            self.cpu_state.regs.pc += 1;
+                            self.cpu_state.regs.pc = pc.wrapping_add(1);
                         },
                         2 => {
-                            self.cpu_state.o1 = data.expect("Bus data can't be empty in fetch cycle 2");
-                            self.cpu_state.regs.pc += 1;
+                            self.cpu_state.o1 = data?;
+                            self.cpu_state.regs.pc = pc.wrapping_add(1);
                         },
                         3 => {
-                            self.cpu_state.o2 = data.expect("Bus data can't be empty in fetch cycle 3");
-                            self.cpu_state.regs.pc += 1;
+                            self.cpu_state.o2 = data?;
+                            self.cpu_state.regs.pc = pc.wrapping_add(1);
                         },
-                        _ => panic!("Fetch state can't take longer than 3 cycles"),
+                        _ => return Err(CpuError::FetchOverrun{opcode: self.cpu_state.op, pc}),
                     }
 
-                    if self.emu_state.op_cycle < self.instruction.expect("CPU::instruction cant be None after decoding").bytes {
-                        return Read{addr: self.cpu_state.regs.pc};
+                    let mut bytes = self.instruction
+                                        .ok_or(CpuError::InvalidState("no instruction after decoding"))?
+                                        .bytes;
+
+                    // JSR reads the high byte of its target during addressing, see addressing::abs_jsr
+                    if self.cpu_state.op == Opcode::JSR_abs as u8 {
+                        bytes = 2;
+                    }
+
+                    if self.emu_state.op_cycle < bytes {
+                        return Ok(Read{addr: self.cpu_state.regs.pc});
                     } else {
                         self.exec_state = Addressing;
                     }
@@ -230,43 +254,52 @@ impl CPU for CpuInterpreter {
                     self.addr_cycle += 1;
 
                     let instruction = self.instruction
-                                        .expect("CPU::instruction is None, this should be impossible at this point");
+                                        .ok_or(CpuError::InvalidState("no instruction in addressing"))?;
 
                     match (instruction.addr_delegate)(&mut self.cpu_state, self.addr_cycle) {
                         AddrDelegateReturn::Yield(msg) => {
-                            return msg;
+                            return Ok(msg);
                         }
                         AddrDelegateReturn::Return(operand) => {
                             self.operand = Some(operand);
                             self.exec_state = Execute;
                             continue;
                         }
+                        AddrDelegateReturn::Overrun => {
+                            return Err(CpuError::AddressingOverrun{opcode: self.cpu_state.op, cycle: self.addr_cycle});
+                        }
                     }
                 }
                 CpuInterpreterState::Execute => {
                     self.exec_cycle += 1;
-                    
+
                     let instruction = self.instruction
-                                        .expect("CPU::instruction is None, this should be impossible at this point");
+                                        .ok_or(CpuError::InvalidState("no instruction in execution"))?;
 
                     let operand = self.operand
                                         .as_ref()
-                                        .expect("CPU::operand can't be None after addressing");
-                    
+                                        .ok_or(CpuError::InvalidState("no operand after addressing"))?;
+
+                    let mismatch = CpuError::OperandMismatch{opcode: self.cpu_state.op};
+
                     let msg: BusMessage = match operand {
                         Operand::Implied => {
-                            instruction.op_delegate.implied().unwrap()(&mut self.cpu_state, self.exec_cycle)
+                            instruction.op_delegate.implied().ok_or(mismatch)?(&mut self.cpu_state, self.exec_cycle)
                         }
                         Operand::Immediate(imm) => {
-                            instruction.op_delegate.immediate().unwrap()(&mut self.cpu_state, *imm, self.exec_cycle)
+                            instruction.op_delegate.immediate().ok_or(mismatch)?(&mut self.cpu_state, *imm, self.exec_cycle)
                         }
                         Operand::Address(addr) => {
-                            instruction.op_delegate.address().unwrap()(&mut self.cpu_state, *addr, self.exec_cycle)
+                            instruction.op_delegate.address().ok_or(mismatch)?(&mut self.cpu_state, *addr, self.exec_cycle)
                         }
                     };
 
+                    if self.cpu_state.halt {
+                        return Err(CpuError::Jammed{opcode: self.cpu_state.op, pc: self.cpu_state.regs.pc.wrapping_sub(1)});
+                    }
+
                     if self.emu_state.op_cycle < instruction.cycles + self.emu_state.additional_cycles || self.cpu_state.extra_cycle {
-                        return msg;
+                        return Ok(msg);
                     } else {
                         // We're done with this instruction, prepare the next one!
                         self.emu_state.op_cycle = 0;
@@ -280,6 +313,11 @@ impl CPU for CpuInterpreter {
                             Interrupt::None => {
                                 self.exec_state = Fetch;
                             }
+                            // an IRQ latched while the interrupt disable flag was being set gets dropped
+                            Interrupt::Irq(_) if self.cpu_state.regs.get_flag(CpuFlags::I) => {
+                                self.exec_state = Fetch;
+                                self.emu_state.interrupt_request = Interrupt::None;
+                            }
                             Interrupt::Irq(interrupt_vector) => {
                                 self.instruction = Some(&instructions::IRQ_INSTRUCTION);
                                 self.operand = Some(Operand::Address(interrupt_vector));
@@ -294,16 +332,55 @@ impl CPU for CpuInterpreter {
                             }
                         }
 
-                        return Read{addr: self.cpu_state.regs.pc};
+                        return Ok(Read{addr: self.cpu_state.regs.pc});
                     }
                 }
                 CpuInterpreterState::Halt => {
-                    println!("CPU is halted");
-                    return Nop;
+                    return Ok(Nop);
                 }
             }
         }
     }
+}
+
+impl Default for CpuInterpreter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CPU for CpuInterpreter {
+
+    fn clock(&mut self, data: Option<u8>) -> Result<BusMessage, CpuError> {
+
+        self.emu_state.instruction_done = false;
+        self.emu_state.total_cycles += 1;
+        self.emu_state.op_cycle += 1;
+
+        if let Some(data) = data {
+            self.cpu_state.data = data;
+        }
+
+        // increase the cycle lenght of the current instruction if the current instruction requires it
+        if self.cpu_state.extra_cycle {
+            self.emu_state.additional_cycles += 1;
+            self.cpu_state.extra_cycle = false;
+        }
+
+        let result = self.run_state_machine(data);
+
+        if let Err(error) = result {
+            self.jam(error);
+        }
+
+        result
+    }
+
+    fn stall(&mut self) {
+        // halted by an external agent like the DMA unit, only time passes
+        self.emu_state.total_cycles += 1;
+        self.emu_state.instruction_done = false;
+    }
 
     fn irq(&mut self) {
         if !self.cpu_state.regs.get_flag(CpuFlags::I) && self.emu_state.interrupt_request == Interrupt::None {
@@ -320,6 +397,7 @@ impl CPU for CpuInterpreter {
         self.emu_state = EmulationState::default();
         self.addr_cycle = 0;
         self.exec_cycle = 0;
+        self.cpu_state.halt = false;
 
         // bit 3 of status always high
         self.cpu_state.regs.status = 0x20;
@@ -11,6 +11,7 @@ pub struct Instruction {
     pub op_delegate: OpDelegate,
     pub mnemonic: &'static str,
     pub addressing: &'static str,
+    pub unofficial: bool,
 }
 
 pub enum Operand {
@@ -28,7 +29,7 @@ pub enum OpDelegate {
 impl OpDelegate {
     pub fn implied(&self) -> Option<&OpDelegateImplied> {
         if let Self::Implied(delegate) = self {
-            Some(&delegate)
+            Some(delegate)
         } else {
             None
         }
@@ -36,7 +37,7 @@ impl OpDelegate {
 
     pub fn immediate(&self) -> Option<&OpDelegateImmediate> {
         if let Self::Immediate(delegate) = self {
-            Some(&delegate)
+            Some(delegate)
         } else {
             None
         }
@@ -44,7 +45,7 @@ impl OpDelegate {
 
     pub fn address(&self) -> Option<&OpDelegateAddress> {
         if let Self::Address(delegate) = self {
-            Some(&delegate)
+            Some(delegate)
         } else {
             None
         }
@@ -54,6 +55,7 @@ impl OpDelegate {
 pub enum AddrDelegateReturn {
     Yield(BusMessage),
     Return(Operand),
+    Overrun,    // called again after returning the operand
 }
 
 pub type AddrDelegate = fn(&mut CpuState, u8) -> AddrDelegateReturn;
@@ -68,24 +70,27 @@ pub static RESET_INSTRUCTION: Instruction = Instruction {
     op_delegate: OpDelegate::Address(ops::reset),
     mnemonic: "RESET",
     addressing: "Reset Vector",
+    unofficial: false,
 };
 
 pub static IRQ_INSTRUCTION: Instruction = Instruction {
-    cycles: 8,
+    cycles: 7,
     bytes: 0,
     addr_delegate: addressing::imp, // addressing should be skipped altogether
     op_delegate: OpDelegate::Address(ops::interrupt),
     mnemonic: "IRQ",
     addressing: "IRQ Vector",
+    unofficial: false,
 };
 
 pub static NMI_INSTRUCTION: Instruction = Instruction {
-    cycles: 8,
+    cycles: 7,
     bytes: 0,
     addr_delegate: addressing::imp, // addressing should be skipped altogether
     op_delegate: OpDelegate::Address(ops::interrupt),
     mnemonic: "NMI",
     addressing: "NMI Vector",
+    unofficial: false,
 };
 
 // ⚠️ here be automatically generated dragons 🐉
@@ -96,161 +101,266 @@ pub static NMI_INSTRUCTION: Instruction = Instruction {
 pub enum Opcode {
     BRK_imp = 0x00,
     ORA_ind_x = 0x01,
+    KIL_imp_02 = 0x02,
+    SLO_ind_x = 0x03,
+    NOP_zp_04 = 0x04,
     ORA_zp = 0x05,
     ASL_zp = 0x06,
+    SLO_zp = 0x07,
     PHP_imp = 0x08,
     ORA_imm = 0x09,
     ASL_acc = 0x0a,
+    ANC_imm_0b = 0x0b,
+    NOP_abs = 0x0c,
     ORA_abs = 0x0d,
     ASL_abs = 0x0e,
+    SLO_abs = 0x0f,
     BPL_rel = 0x10,
     ORA_ind_y = 0x11,
+    KIL_imp_12 = 0x12,
+    SLO_ind_y = 0x13,
+    NOP_zp_x_14 = 0x14,
     ORA_zp_x = 0x15,
     ASL_zp_x = 0x16,
+    SLO_zp_x = 0x17,
     CLC_imp = 0x18,
     ORA_abs_y = 0x19,
+    NOP_imp_1a = 0x1a,
+    SLO_abs_y = 0x1b,
+    NOP_abs_x_1c = 0x1c,
     ORA_abs_x = 0x1d,
     ASL_abs_x = 0x1e,
+    SLO_abs_x = 0x1f,
     JSR_abs = 0x20,
     AND_ind_x = 0x21,
+    KIL_imp_22 = 0x22,
+    RLA_ind_x = 0x23,
     BIT_zp = 0x24,
     AND_zp = 0x25,
     ROL_zp = 0x26,
+    RLA_zp = 0x27,
     PLP_imp = 0x28,
     AND_imm = 0x29,
     ROL_acc = 0x2a,
+    ANC_imm_2b = 0x2b,
     BIT_abs = 0x2c,
     AND_abs = 0x2d,
     ROL_abs = 0x2e,
+    RLA_abs = 0x2f,
     BMI_rel = 0x30,
     AND_ind_y = 0x31,
+    KIL_imp_32 = 0x32,
+    RLA_ind_y = 0x33,
+    NOP_zp_x_34 = 0x34,
     AND_zp_x = 0x35,
     ROL_zp_x = 0x36,
+    RLA_zp_x = 0x37,
     SEC_imp = 0x38,
     AND_abs_y = 0x39,
+    NOP_imp_3a = 0x3a,
+    RLA_abs_y = 0x3b,
+    NOP_abs_x_3c = 0x3c,
     AND_abs_x = 0x3d,
     ROL_abs_x = 0x3e,
+    RLA_abs_x = 0x3f,
     RTI_imp = 0x40,
     EOR_ind_x = 0x41,
+    KIL_imp_42 = 0x42,
+    SRE_ind_x = 0x43,
+    NOP_zp_44 = 0x44,
     EOR_zp = 0x45,
     LSR_zp = 0x46,
+    SRE_zp = 0x47,
     PHA_imp = 0x48,
     EOR_imm = 0x49,
     LSR_acc = 0x4a,
+    ALR_imm = 0x4b,
     JMP_abs = 0x4c,
     EOR_abs = 0x4d,
     LSR_abs = 0x4e,
+    SRE_abs = 0x4f,
     BVC_rel = 0x50,
     EOR_ind_y = 0x51,
+    KIL_imp_52 = 0x52,
+    SRE_ind_y = 0x53,
+    NOP_zp_x_54 = 0x54,
     EOR_zp_x = 0x55,
     LSR_zp_x = 0x56,
+    SRE_zp_x = 0x57,
     CLI_imp = 0x58,
     EOR_abs_y = 0x59,
+    NOP_imp_5a = 0x5a,
+    SRE_abs_y = 0x5b,
+    NOP_abs_x_5c = 0x5c,
     EOR_abs_x = 0x5d,
     LSR_abs_x = 0x5e,
+    SRE_abs_x = 0x5f,
     RTS_imp = 0x60,
     ADC_ind_x = 0x61,
+    KIL_imp_62 = 0x62,
+    RRA_ind_x = 0x63,
+    NOP_zp_64 = 0x64,
     ADC_zp = 0x65,
     ROR_zp = 0x66,
+    RRA_zp = 0x67,
     PLA_imp = 0x68,
     ADC_imm = 0x69,
     ROR_acc = 0x6a,
+    ARR_imm = 0x6b,
     JMP_ind = 0x6c,
     ADC_abs = 0x6d,
     ROR_abs = 0x6e,
+    RRA_abs = 0x6f,
     BVS_rel = 0x70,
     ADC_ind_y = 0x71,
+    KIL_imp_72 = 0x72,
+    RRA_ind_y = 0x73,
+    NOP_zp_x_74 = 0x74,
     ADC_zp_x = 0x75,
     ROR_zp_x = 0x76,
+    RRA_zp_x = 0x77,
     SEI_imp = 0x78,
     ADC_abs_y = 0x79,
+    NOP_imp_7a = 0x7a,
+    RRA_abs_y = 0x7b,
+    NOP_abs_x_7c = 0x7c,
     ADC_abs_x = 0x7d,
     ROR_abs_x = 0x7e,
+    RRA_abs_x = 0x7f,
+    NOP_imm_80 = 0x80,
     STA_ind_x = 0x81,
+    NOP_imm_82 = 0x82,
+    SAX_ind_x = 0x83,
     STY_zp = 0x84,
     STA_zp = 0x85,
     STX_zp = 0x86,
+    SAX_zp = 0x87,
     DEY_imp = 0x88,
+    NOP_imm_89 = 0x89,
     TXA_imp = 0x8a,
+    XAA_imm = 0x8b,
     STY_abs = 0x8c,
     STA_abs = 0x8d,
     STX_abs = 0x8e,
+    SAX_abs = 0x8f,
     BCC_rel = 0x90,
     STA_ind_y = 0x91,
+    KIL_imp_92 = 0x92,
+    SHA_ind_y = 0x93,
     STY_zp_x = 0x94,
     STA_zp_x = 0x95,
     STX_zp_y = 0x96,
+    SAX_zp_y = 0x97,
     TYA_imp = 0x98,
     STA_abs_y = 0x99,
     TXS_imp = 0x9a,
+    TAS_abs_y = 0x9b,
+    SHY_abs_x = 0x9c,
     STA_abs_x = 0x9d,
+    SHX_abs_y = 0x9e,
+    SHA_abs_y = 0x9f,
     LDY_imm = 0xa0,
     LDA_ind_x = 0xa1,
     LDX_imm = 0xa2,
+    LAX_ind_x = 0xa3,
     LDY_zp = 0xa4,
     LDA_zp = 0xa5,
     LDX_zp = 0xa6,
+    LAX_zp = 0xa7,
     TAY_imp = 0xa8,
     LDA_imm = 0xa9,
     TAX_imp = 0xaa,
+    LAX_imm = 0xab,
     LDY_abs = 0xac,
     LDA_abs = 0xad,
     LDX_abs = 0xae,
+    LAX_abs = 0xaf,
     BCS_rel = 0xb0,
     LDA_ind_y = 0xb1,
+    KIL_imp_b2 = 0xb2,
+    LAX_ind_y = 0xb3,
     LDY_zp_x = 0xb4,
     LDA_zp_x = 0xb5,
     LDX_zp_y = 0xb6,
+    LAX_zp_y = 0xb7,
     CLV_imp = 0xb8,
     LDA_abs_y = 0xb9,
     TSX_imp = 0xba,
+    LAS_abs_y = 0xbb,
     LDY_abs_x = 0xbc,
     LDA_abs_x = 0xbd,
     LDX_abs_y = 0xbe,
+    LAX_abs_y = 0xbf,
     CPY_imm = 0xc0,
     CMP_ind_x = 0xc1,
+    NOP_imm_c2 = 0xc2,
+    DCP_ind_x = 0xc3,
     CPY_zp = 0xc4,
     CMP_zp = 0xc5,
     DEC_zp = 0xc6,
+    DCP_zp = 0xc7,
     INY_imp = 0xc8,
     CMP_imm = 0xc9,
     DEX_imp = 0xca,
+    AXS_imm = 0xcb,
     CPY_abs = 0xcc,
     CMP_abs = 0xcd,
     DEC_abs = 0xce,
+    DCP_abs = 0xcf,
     BNE_rel = 0xd0,
     CMP_ind_y = 0xd1,
+    KIL_imp_d2 = 0xd2,
+    DCP_ind_y = 0xd3,
+    NOP_zp_x_d4 = 0xd4,
     CMP_zp_x = 0xd5,
     DEC_zp_x = 0xd6,
+    DCP_zp_x = 0xd7,
     CLD_imp = 0xd8,
     CMP_abs_y = 0xd9,
+    NOP_imp_da = 0xda,
+    DCP_abs_y = 0xdb,
+    NOP_abs_x_dc = 0xdc,
     CMP_abs_x = 0xdd,
     DEC_abs_x = 0xde,
+    DCP_abs_x = 0xdf,
     CPX_imm = 0xe0,
     SBC_ind_x = 0xe1,
+    NOP_imm_e2 = 0xe2,
+    ISC_ind_x = 0xe3,
     CPX_zp = 0xe4,
     SBC_zp = 0xe5,
     INC_zp = 0xe6,
+    ISC_zp = 0xe7,
     INX_imp = 0xe8,
     SBC_imm = 0xe9,
     NOP_imp = 0xea,
+    SBC_imm_eb = 0xeb,
     CPX_abs = 0xec,
     SBC_abs = 0xed,
     INC_abs = 0xee,
+    ISC_abs = 0xef,
     BEQ_rel = 0xf0,
     SBC_ind_y = 0xf1,
+    KIL_imp_f2 = 0xf2,
+    ISC_ind_y = 0xf3,
+    NOP_zp_x_f4 = 0xf4,
     SBC_zp_x = 0xf5,
     INC_zp_x = 0xf6,
+    ISC_zp_x = 0xf7,
     SED_imp = 0xf8,
     SBC_abs_y = 0xf9,
+    NOP_imp_fa = 0xfa,
+    ISC_abs_y = 0xfb,
+    NOP_abs_x_fc = 0xfc,
     SBC_abs_x = 0xfd,
     INC_abs_x = 0xfe,
+    ISC_abs_x = 0xff,
 }
 
 impl Opcode {
     pub fn to_instruction(&self) -> &'static Instruction {
         match self {
-            
+    
             Opcode::BRK_imp => {
                 &Instruction{
                     cycles: 7,
@@ -259,9 +369,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::brk_implied),
                     mnemonic: "BRK",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ORA_ind_x => {
                 &Instruction{
                     cycles: 6,
@@ -270,9 +381,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ora_address),
                     mnemonic: "ORA",
                     addressing: "(IND, X)",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::KIL_imp_02 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::SLO_ind_x => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_x,
+                    op_delegate: OpDelegate::Address(ops::slo_address),
+                    mnemonic: "SLO",
+                    addressing: "(IND, X)",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_zp_04 => {
+                &Instruction{
+                    cycles: 3,
+                    bytes: 2,
+                    addr_delegate: addressing::zp,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ZP",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::ORA_zp => {
                 &Instruction{
                     cycles: 3,
@@ -281,9 +429,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ora_address),
                     mnemonic: "ORA",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ASL_zp => {
                 &Instruction{
                     cycles: 5,
@@ -292,9 +441,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::asl_address),
                     mnemonic: "ASL",
                     addressing: "ZP",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::SLO_zp => {
+                &Instruction{
+                    cycles: 5,
+                    bytes: 2,
+                    addr_delegate: addressing::zp,
+                    op_delegate: OpDelegate::Address(ops::slo_address),
+                    mnemonic: "SLO",
+                    addressing: "ZP",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::PHP_imp => {
                 &Instruction{
                     cycles: 3,
@@ -303,9 +465,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::php_implied),
                     mnemonic: "PHP",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ORA_imm => {
                 &Instruction{
                     cycles: 2,
@@ -314,9 +477,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Immediate(ops::ora_immediate),
                     mnemonic: "ORA",
                     addressing: "IMM",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ASL_acc => {
                 &Instruction{
                     cycles: 2,
@@ -325,9 +489,34 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::asl_implied),
                     mnemonic: "ASL",
                     addressing: "Accum",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::ANC_imm_0b => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::anc_immediate),
+                    mnemonic: "ANC",
+                    addressing: "IMM",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_abs => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 3,
+                    addr_delegate: addressing::abs,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "Absolute",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::ORA_abs => {
                 &Instruction{
                     cycles: 4,
@@ -336,9 +525,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ora_address),
                     mnemonic: "ORA",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ASL_abs => {
                 &Instruction{
                     cycles: 6,
@@ -347,9 +537,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::asl_address),
                     mnemonic: "ASL",
                     addressing: "Absolute",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::SLO_abs => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 3,
+                    addr_delegate: addressing::abs,
+                    op_delegate: OpDelegate::Address(ops::slo_address),
+                    mnemonic: "SLO",
+                    addressing: "Absolute",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::BPL_rel => {
                 &Instruction{
                     cycles: 2,
@@ -358,9 +561,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::bpl_address),
                     mnemonic: "BPL",
                     addressing: "Relative",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ORA_ind_y => {
                 &Instruction{
                     cycles: 5,
@@ -369,9 +573,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ora_address),
                     mnemonic: "ORA",
                     addressing: "(IND), Y",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::KIL_imp_12 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::SLO_ind_y => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_y,
+                    op_delegate: OpDelegate::Address(ops::slo_address),
+                    mnemonic: "SLO",
+                    addressing: "(IND), Y",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_zp_x_14 => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ZP, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::ORA_zp_x => {
                 &Instruction{
                     cycles: 4,
@@ -380,9 +621,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ora_address),
                     mnemonic: "ORA",
                     addressing: "ZP, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ASL_zp_x => {
                 &Instruction{
                     cycles: 6,
@@ -391,9 +633,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::asl_address),
                     mnemonic: "ASL",
                     addressing: "ZP, X",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::SLO_zp_x => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::slo_address),
+                    mnemonic: "SLO",
+                    addressing: "ZP, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::CLC_imp => {
                 &Instruction{
                     cycles: 2,
@@ -402,9 +657,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::clc_implied),
                     mnemonic: "CLC",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ORA_abs_y => {
                 &Instruction{
                     cycles: 4,
@@ -413,9 +669,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ora_address),
                     mnemonic: "ORA",
                     addressing: "ABS, Y",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::NOP_imp_1a => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::nop_implied),
+                    mnemonic: "NOP",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::SLO_abs_y => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_y,
+                    op_delegate: OpDelegate::Address(ops::slo_address),
+                    mnemonic: "SLO",
+                    addressing: "ABS, Y",
+                    unofficial: true,
                 }
             }
-            
+    
+            Opcode::NOP_abs_x_1c => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x_extra,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ABS, X",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::ORA_abs_x => {
                 &Instruction{
                     cycles: 4,
@@ -424,9 +717,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ora_address),
                     mnemonic: "ORA",
                     addressing: "ABS, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ASL_abs_x => {
                 &Instruction{
                     cycles: 7,
@@ -435,20 +729,34 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::asl_address),
                     mnemonic: "ASL",
                     addressing: "ABS, X",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::SLO_abs_x => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x,
+                    op_delegate: OpDelegate::Address(ops::slo_address),
+                    mnemonic: "SLO",
+                    addressing: "ABS, X",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::JSR_abs => {
                 &Instruction{
                     cycles: 6,
                     bytes: 3,
-                    addr_delegate: addressing::abs,
+                    addr_delegate: addressing::abs_jsr,
                     op_delegate: OpDelegate::Address(ops::jsr_address),
                     mnemonic: "JSR",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::AND_ind_x => {
                 &Instruction{
                     cycles: 6,
@@ -457,9 +765,34 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::and_address),
                     mnemonic: "AND",
                     addressing: "(IND, X)",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::KIL_imp_22 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::RLA_ind_x => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_x,
+                    op_delegate: OpDelegate::Address(ops::rla_address),
+                    mnemonic: "RLA",
+                    addressing: "(IND, X)",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::BIT_zp => {
                 &Instruction{
                     cycles: 3,
@@ -468,9 +801,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::bit_address),
                     mnemonic: "BIT",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::AND_zp => {
                 &Instruction{
                     cycles: 3,
@@ -479,9 +813,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::and_address),
                     mnemonic: "AND",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ROL_zp => {
                 &Instruction{
                     cycles: 5,
@@ -490,9 +825,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::rol_address),
                     mnemonic: "ROL",
                     addressing: "ZP",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::RLA_zp => {
+                &Instruction{
+                    cycles: 5,
+                    bytes: 2,
+                    addr_delegate: addressing::zp,
+                    op_delegate: OpDelegate::Address(ops::rla_address),
+                    mnemonic: "RLA",
+                    addressing: "ZP",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::PLP_imp => {
                 &Instruction{
                     cycles: 4,
@@ -501,9 +849,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::plp_implied),
                     mnemonic: "PLP",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::AND_imm => {
                 &Instruction{
                     cycles: 2,
@@ -512,9 +861,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Immediate(ops::and_immediate),
                     mnemonic: "AND",
                     addressing: "IMM",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ROL_acc => {
                 &Instruction{
                     cycles: 2,
@@ -523,9 +873,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::rol_implied),
                     mnemonic: "ROL",
                     addressing: "Accum",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::ANC_imm_2b => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::anc_immediate),
+                    mnemonic: "ANC",
+                    addressing: "IMM",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::BIT_abs => {
                 &Instruction{
                     cycles: 4,
@@ -534,9 +897,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::bit_address),
                     mnemonic: "BIT",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::AND_abs => {
                 &Instruction{
                     cycles: 4,
@@ -545,9 +909,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::and_address),
                     mnemonic: "AND",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ROL_abs => {
                 &Instruction{
                     cycles: 6,
@@ -556,9 +921,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::rol_address),
                     mnemonic: "ROL",
                     addressing: "Absolute",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::RLA_abs => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 3,
+                    addr_delegate: addressing::abs,
+                    op_delegate: OpDelegate::Address(ops::rla_address),
+                    mnemonic: "RLA",
+                    addressing: "Absolute",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::BMI_rel => {
                 &Instruction{
                     cycles: 2,
@@ -567,9 +945,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::bmi_address),
                     mnemonic: "BMI",
                     addressing: "Relative",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::AND_ind_y => {
                 &Instruction{
                     cycles: 5,
@@ -578,9 +957,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::and_address),
                     mnemonic: "AND",
                     addressing: "(IND), Y",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::KIL_imp_32 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::RLA_ind_y => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_y,
+                    op_delegate: OpDelegate::Address(ops::rla_address),
+                    mnemonic: "RLA",
+                    addressing: "(IND), Y",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_zp_x_34 => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ZP, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::AND_zp_x => {
                 &Instruction{
                     cycles: 4,
@@ -589,9 +1005,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::and_address),
                     mnemonic: "AND",
                     addressing: "ZP, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ROL_zp_x => {
                 &Instruction{
                     cycles: 6,
@@ -600,9 +1017,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::rol_address),
                     mnemonic: "ROL",
                     addressing: "ZP, X",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::RLA_zp_x => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::rla_address),
+                    mnemonic: "RLA",
+                    addressing: "ZP, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::SEC_imp => {
                 &Instruction{
                     cycles: 2,
@@ -611,9 +1041,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::sec_implied),
                     mnemonic: "SEC",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::AND_abs_y => {
                 &Instruction{
                     cycles: 4,
@@ -622,9 +1053,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::and_address),
                     mnemonic: "AND",
                     addressing: "ABS, Y",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::NOP_imp_3a => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::nop_implied),
+                    mnemonic: "NOP",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::RLA_abs_y => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_y,
+                    op_delegate: OpDelegate::Address(ops::rla_address),
+                    mnemonic: "RLA",
+                    addressing: "ABS, Y",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_abs_x_3c => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x_extra,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ABS, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::AND_abs_x => {
                 &Instruction{
                     cycles: 4,
@@ -633,9 +1101,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::and_address),
                     mnemonic: "AND",
                     addressing: "ABS, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ROL_abs_x => {
                 &Instruction{
                     cycles: 7,
@@ -644,9 +1113,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::rol_address),
                     mnemonic: "ROL",
                     addressing: "ABS, X",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::RLA_abs_x => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x,
+                    op_delegate: OpDelegate::Address(ops::rla_address),
+                    mnemonic: "RLA",
+                    addressing: "ABS, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::RTI_imp => {
                 &Instruction{
                     cycles: 6,
@@ -655,9 +1137,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::rti_implied),
                     mnemonic: "RTI",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::EOR_ind_x => {
                 &Instruction{
                     cycles: 6,
@@ -666,9 +1149,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::eor_address),
                     mnemonic: "EOR",
                     addressing: "(IND, X)",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::KIL_imp_42 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::SRE_ind_x => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_x,
+                    op_delegate: OpDelegate::Address(ops::sre_address),
+                    mnemonic: "SRE",
+                    addressing: "(IND, X)",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_zp_44 => {
+                &Instruction{
+                    cycles: 3,
+                    bytes: 2,
+                    addr_delegate: addressing::zp,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ZP",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::EOR_zp => {
                 &Instruction{
                     cycles: 3,
@@ -677,9 +1197,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::eor_address),
                     mnemonic: "EOR",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LSR_zp => {
                 &Instruction{
                     cycles: 5,
@@ -688,9 +1209,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::lsr_address),
                     mnemonic: "LSR",
                     addressing: "ZP",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::SRE_zp => {
+                &Instruction{
+                    cycles: 5,
+                    bytes: 2,
+                    addr_delegate: addressing::zp,
+                    op_delegate: OpDelegate::Address(ops::sre_address),
+                    mnemonic: "SRE",
+                    addressing: "ZP",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::PHA_imp => {
                 &Instruction{
                     cycles: 3,
@@ -699,9 +1233,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::pha_implied),
                     mnemonic: "PHA",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::EOR_imm => {
                 &Instruction{
                     cycles: 2,
@@ -710,9 +1245,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Immediate(ops::eor_immediate),
                     mnemonic: "EOR",
                     addressing: "IMM",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LSR_acc => {
                 &Instruction{
                     cycles: 2,
@@ -721,9 +1257,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::lsr_implied),
                     mnemonic: "LSR",
                     addressing: "Accum",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::ALR_imm => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::alr_immediate),
+                    mnemonic: "ALR",
+                    addressing: "IMM",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::JMP_abs => {
                 &Instruction{
                     cycles: 3,
@@ -732,9 +1281,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::jmp_address),
                     mnemonic: "JMP",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::EOR_abs => {
                 &Instruction{
                     cycles: 4,
@@ -743,9 +1293,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::eor_address),
                     mnemonic: "EOR",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LSR_abs => {
                 &Instruction{
                     cycles: 6,
@@ -754,9 +1305,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::lsr_address),
                     mnemonic: "LSR",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::SRE_abs => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 3,
+                    addr_delegate: addressing::abs,
+                    op_delegate: OpDelegate::Address(ops::sre_address),
+                    mnemonic: "SRE",
+                    addressing: "Absolute",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::BVC_rel => {
                 &Instruction{
                     cycles: 2,
@@ -765,9 +1329,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::bvc_address),
                     mnemonic: "BVC",
                     addressing: "Relative",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::EOR_ind_y => {
                 &Instruction{
                     cycles: 5,
@@ -776,20 +1341,58 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::eor_address),
                     mnemonic: "EOR",
                     addressing: "(IND), Y",
+                    unofficial: false,
                 }
             }
-            
-            Opcode::EOR_zp_x => {
+    
+            Opcode::KIL_imp_52 => {
                 &Instruction{
-                    cycles: 4,
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::SRE_ind_y => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_y,
+                    op_delegate: OpDelegate::Address(ops::sre_address),
+                    mnemonic: "SRE",
+                    addressing: "(IND), Y",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_zp_x_54 => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ZP, X",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::EOR_zp_x => {
+                &Instruction{
+                    cycles: 4,
                     bytes: 2,
                     addr_delegate: addressing::zp_x,
                     op_delegate: OpDelegate::Address(ops::eor_address),
                     mnemonic: "EOR",
                     addressing: "ZP, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LSR_zp_x => {
                 &Instruction{
                     cycles: 6,
@@ -798,9 +1401,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::lsr_address),
                     mnemonic: "LSR",
                     addressing: "ZP, X",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::SRE_zp_x => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::sre_address),
+                    mnemonic: "SRE",
+                    addressing: "ZP, X",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::CLI_imp => {
                 &Instruction{
                     cycles: 2,
@@ -809,9 +1425,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::cli_implied),
                     mnemonic: "CLI",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::EOR_abs_y => {
                 &Instruction{
                     cycles: 4,
@@ -820,9 +1437,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::eor_address),
                     mnemonic: "EOR",
                     addressing: "ABS, Y",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::NOP_imp_5a => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::nop_implied),
+                    mnemonic: "NOP",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::SRE_abs_y => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_y,
+                    op_delegate: OpDelegate::Address(ops::sre_address),
+                    mnemonic: "SRE",
+                    addressing: "ABS, Y",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_abs_x_5c => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x_extra,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ABS, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::EOR_abs_x => {
                 &Instruction{
                     cycles: 4,
@@ -831,9 +1485,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::eor_address),
                     mnemonic: "EOR",
                     addressing: "ABS, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LSR_abs_x => {
                 &Instruction{
                     cycles: 7,
@@ -842,9 +1497,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::lsr_address),
                     mnemonic: "LSR",
                     addressing: "ABS, X",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::SRE_abs_x => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x,
+                    op_delegate: OpDelegate::Address(ops::sre_address),
+                    mnemonic: "SRE",
+                    addressing: "ABS, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::RTS_imp => {
                 &Instruction{
                     cycles: 6,
@@ -853,9 +1521,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::rts_implied),
                     mnemonic: "RTS",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ADC_ind_x => {
                 &Instruction{
                     cycles: 6,
@@ -864,9 +1533,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::adc_address),
                     mnemonic: "ADC",
                     addressing: "(IND, X)",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::KIL_imp_62 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::RRA_ind_x => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_x,
+                    op_delegate: OpDelegate::Address(ops::rra_address),
+                    mnemonic: "RRA",
+                    addressing: "(IND, X)",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_zp_64 => {
+                &Instruction{
+                    cycles: 3,
+                    bytes: 2,
+                    addr_delegate: addressing::zp,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ZP",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::ADC_zp => {
                 &Instruction{
                     cycles: 3,
@@ -875,9 +1581,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::adc_address),
                     mnemonic: "ADC",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ROR_zp => {
                 &Instruction{
                     cycles: 5,
@@ -886,9 +1593,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ror_address),
                     mnemonic: "ROR",
                     addressing: "ZP",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::RRA_zp => {
+                &Instruction{
+                    cycles: 5,
+                    bytes: 2,
+                    addr_delegate: addressing::zp,
+                    op_delegate: OpDelegate::Address(ops::rra_address),
+                    mnemonic: "RRA",
+                    addressing: "ZP",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::PLA_imp => {
                 &Instruction{
                     cycles: 4,
@@ -897,9 +1617,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::pla_implied),
                     mnemonic: "PLA",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ADC_imm => {
                 &Instruction{
                     cycles: 2,
@@ -908,9 +1629,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Immediate(ops::adc_immediate),
                     mnemonic: "ADC",
                     addressing: "IMM",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ROR_acc => {
                 &Instruction{
                     cycles: 2,
@@ -919,9 +1641,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::ror_implied),
                     mnemonic: "ROR",
                     addressing: "Accum",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::ARR_imm => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::arr_immediate),
+                    mnemonic: "ARR",
+                    addressing: "IMM",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::JMP_ind => {
                 &Instruction{
                     cycles: 5,
@@ -930,9 +1665,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::jmp_address),
                     mnemonic: "JMP",
                     addressing: "Indirect",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ADC_abs => {
                 &Instruction{
                     cycles: 4,
@@ -941,9 +1677,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::adc_address),
                     mnemonic: "ADC",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ROR_abs => {
                 &Instruction{
                     cycles: 6,
@@ -952,9 +1689,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ror_address),
                     mnemonic: "ROR",
                     addressing: "Absolute",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::RRA_abs => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 3,
+                    addr_delegate: addressing::abs,
+                    op_delegate: OpDelegate::Address(ops::rra_address),
+                    mnemonic: "RRA",
+                    addressing: "Absolute",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::BVS_rel => {
                 &Instruction{
                     cycles: 2,
@@ -963,9 +1713,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::bvs_address),
                     mnemonic: "BVS",
                     addressing: "Relative",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ADC_ind_y => {
                 &Instruction{
                     cycles: 5,
@@ -974,9 +1725,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::adc_address),
                     mnemonic: "ADC",
                     addressing: "(IND), Y",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::KIL_imp_72 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::RRA_ind_y => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_y,
+                    op_delegate: OpDelegate::Address(ops::rra_address),
+                    mnemonic: "RRA",
+                    addressing: "(IND), Y",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_zp_x_74 => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ZP, X",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::ADC_zp_x => {
                 &Instruction{
                     cycles: 4,
@@ -985,9 +1773,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::adc_address),
                     mnemonic: "ADC",
                     addressing: "ZP, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ROR_zp_x => {
                 &Instruction{
                     cycles: 6,
@@ -996,9 +1785,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ror_address),
                     mnemonic: "ROR",
                     addressing: "ZP, X",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::RRA_zp_x => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::rra_address),
+                    mnemonic: "RRA",
+                    addressing: "ZP, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::SEI_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1007,9 +1809,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::sei_implied),
                     mnemonic: "SEI",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ADC_abs_y => {
                 &Instruction{
                     cycles: 4,
@@ -1018,9 +1821,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::adc_address),
                     mnemonic: "ADC",
                     addressing: "ABS, Y",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::NOP_imp_7a => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::nop_implied),
+                    mnemonic: "NOP",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::RRA_abs_y => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_y,
+                    op_delegate: OpDelegate::Address(ops::rra_address),
+                    mnemonic: "RRA",
+                    addressing: "ABS, Y",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_abs_x_7c => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x_extra,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ABS, X",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::ADC_abs_x => {
                 &Instruction{
                     cycles: 4,
@@ -1029,9 +1869,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::adc_address),
                     mnemonic: "ADC",
                     addressing: "ABS, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::ROR_abs_x => {
                 &Instruction{
                     cycles: 7,
@@ -1040,9 +1881,34 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ror_address),
                     mnemonic: "ROR",
                     addressing: "ABS, X",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::RRA_abs_x => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x,
+                    op_delegate: OpDelegate::Address(ops::rra_address),
+                    mnemonic: "RRA",
+                    addressing: "ABS, X",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_imm_80 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::nop_immediate),
+                    mnemonic: "NOP",
+                    addressing: "IMM",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::STA_ind_x => {
                 &Instruction{
                     cycles: 6,
@@ -1051,9 +1917,34 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sta_address),
                     mnemonic: "STA",
                     addressing: "(IND, X)",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::NOP_imm_82 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::nop_immediate),
+                    mnemonic: "NOP",
+                    addressing: "IMM",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::SAX_ind_x => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_x,
+                    op_delegate: OpDelegate::Address(ops::sax_address),
+                    mnemonic: "SAX",
+                    addressing: "(IND, X)",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::STY_zp => {
                 &Instruction{
                     cycles: 3,
@@ -1062,9 +1953,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sty_address),
                     mnemonic: "STY",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::STA_zp => {
                 &Instruction{
                     cycles: 3,
@@ -1073,9 +1965,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sta_address),
                     mnemonic: "STA",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::STX_zp => {
                 &Instruction{
                     cycles: 3,
@@ -1084,9 +1977,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::stx_address),
                     mnemonic: "STX",
                     addressing: "ZP",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::SAX_zp => {
+                &Instruction{
+                    cycles: 3,
+                    bytes: 2,
+                    addr_delegate: addressing::zp,
+                    op_delegate: OpDelegate::Address(ops::sax_address),
+                    mnemonic: "SAX",
+                    addressing: "ZP",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::DEY_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1095,9 +2001,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::dey_implied),
                     mnemonic: "DEY",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::NOP_imm_89 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::nop_immediate),
+                    mnemonic: "NOP",
+                    addressing: "IMM",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::TXA_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1106,9 +2025,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::txa_implied),
                     mnemonic: "TXA",
                     addressing: "Implied",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::XAA_imm => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::xaa_immediate),
+                    mnemonic: "XAA",
+                    addressing: "IMM",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::STY_abs => {
                 &Instruction{
                     cycles: 4,
@@ -1117,9 +2049,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sty_address),
                     mnemonic: "STY",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::STA_abs => {
                 &Instruction{
                     cycles: 4,
@@ -1128,9 +2061,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sta_address),
                     mnemonic: "STA",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::STX_abs => {
                 &Instruction{
                     cycles: 4,
@@ -1139,9 +2073,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::stx_address),
                     mnemonic: "STX",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::SAX_abs => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 3,
+                    addr_delegate: addressing::abs,
+                    op_delegate: OpDelegate::Address(ops::sax_address),
+                    mnemonic: "SAX",
+                    addressing: "Absolute",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::BCC_rel => {
                 &Instruction{
                     cycles: 2,
@@ -1150,9 +2097,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::bcc_address),
                     mnemonic: "BCC",
                     addressing: "Relative",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::STA_ind_y => {
                 &Instruction{
                     cycles: 6,
@@ -1161,9 +2109,34 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sta_address),
                     mnemonic: "STA",
                     addressing: "(IND), Y",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::KIL_imp_92 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::SHA_ind_y => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_y,
+                    op_delegate: OpDelegate::Address(ops::sha_address),
+                    mnemonic: "SHA",
+                    addressing: "(IND), Y",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::STY_zp_x => {
                 &Instruction{
                     cycles: 4,
@@ -1172,9 +2145,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sty_address),
                     mnemonic: "STY",
                     addressing: "ZP, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::STA_zp_x => {
                 &Instruction{
                     cycles: 4,
@@ -1183,9 +2157,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sta_address),
                     mnemonic: "STA",
                     addressing: "ZP, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::STX_zp_y => {
                 &Instruction{
                     cycles: 4,
@@ -1194,9 +2169,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::stx_address),
                     mnemonic: "STX",
                     addressing: "ZP, Y",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::SAX_zp_y => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_y,
+                    op_delegate: OpDelegate::Address(ops::sax_address),
+                    mnemonic: "SAX",
+                    addressing: "ZP, Y",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::TYA_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1205,9 +2193,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::tya_implied),
                     mnemonic: "TYA",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::STA_abs_y => {
                 &Instruction{
                     cycles: 5,
@@ -1216,9 +2205,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sta_address),
                     mnemonic: "STA",
                     addressing: "ABS, Y",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::TXS_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1227,9 +2217,34 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::txs_implied),
                     mnemonic: "TXS",
                     addressing: "Implied",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::TAS_abs_y => {
+                &Instruction{
+                    cycles: 5,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_y,
+                    op_delegate: OpDelegate::Address(ops::tas_address),
+                    mnemonic: "TAS",
+                    addressing: "ABS, Y",
+                    unofficial: true,
                 }
             }
-            
+    
+            Opcode::SHY_abs_x => {
+                &Instruction{
+                    cycles: 5,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x,
+                    op_delegate: OpDelegate::Address(ops::shy_address),
+                    mnemonic: "SHY",
+                    addressing: "ABS, X",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::STA_abs_x => {
                 &Instruction{
                     cycles: 5,
@@ -1238,9 +2253,34 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sta_address),
                     mnemonic: "STA",
                     addressing: "ABS, X",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::SHX_abs_y => {
+                &Instruction{
+                    cycles: 5,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_y,
+                    op_delegate: OpDelegate::Address(ops::shx_address),
+                    mnemonic: "SHX",
+                    addressing: "ABS, Y",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::SHA_abs_y => {
+                &Instruction{
+                    cycles: 5,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_y,
+                    op_delegate: OpDelegate::Address(ops::sha_address),
+                    mnemonic: "SHA",
+                    addressing: "ABS, Y",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::LDY_imm => {
                 &Instruction{
                     cycles: 2,
@@ -1249,9 +2289,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Immediate(ops::ldy_immediate),
                     mnemonic: "LDY",
                     addressing: "IMM",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDA_ind_x => {
                 &Instruction{
                     cycles: 6,
@@ -1260,9 +2301,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::lda_address),
                     mnemonic: "LDA",
                     addressing: "(IND, X)",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDX_imm => {
                 &Instruction{
                     cycles: 2,
@@ -1271,9 +2313,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Immediate(ops::ldx_immediate),
                     mnemonic: "LDX",
                     addressing: "IMM",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::LAX_ind_x => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_x,
+                    op_delegate: OpDelegate::Address(ops::lax_address),
+                    mnemonic: "LAX",
+                    addressing: "(IND, X)",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::LDY_zp => {
                 &Instruction{
                     cycles: 3,
@@ -1282,9 +2337,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ldy_address),
                     mnemonic: "LDY",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDA_zp => {
                 &Instruction{
                     cycles: 3,
@@ -1293,9 +2349,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::lda_address),
                     mnemonic: "LDA",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDX_zp => {
                 &Instruction{
                     cycles: 3,
@@ -1304,9 +2361,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ldx_address),
                     mnemonic: "LDX",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::LAX_zp => {
+                &Instruction{
+                    cycles: 3,
+                    bytes: 2,
+                    addr_delegate: addressing::zp,
+                    op_delegate: OpDelegate::Address(ops::lax_address),
+                    mnemonic: "LAX",
+                    addressing: "ZP",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::TAY_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1315,9 +2385,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::tay_implied),
                     mnemonic: "TAY",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDA_imm => {
                 &Instruction{
                     cycles: 2,
@@ -1326,9 +2397,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Immediate(ops::lda_immediate),
                     mnemonic: "LDA",
                     addressing: "IMM",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::TAX_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1337,9 +2409,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::tax_implied),
                     mnemonic: "TAX",
                     addressing: "Implied",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::LAX_imm => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::lax_immediate),
+                    mnemonic: "LAX",
+                    addressing: "IMM",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::LDY_abs => {
                 &Instruction{
                     cycles: 4,
@@ -1348,9 +2433,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ldy_address),
                     mnemonic: "LDY",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDA_abs => {
                 &Instruction{
                     cycles: 4,
@@ -1359,9 +2445,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::lda_address),
                     mnemonic: "LDA",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDX_abs => {
                 &Instruction{
                     cycles: 4,
@@ -1370,9 +2457,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ldx_address),
                     mnemonic: "LDX",
                     addressing: "Absolute",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::LAX_abs => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 3,
+                    addr_delegate: addressing::abs,
+                    op_delegate: OpDelegate::Address(ops::lax_address),
+                    mnemonic: "LAX",
+                    addressing: "Absolute",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::BCS_rel => {
                 &Instruction{
                     cycles: 2,
@@ -1381,9 +2481,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::bcs_address),
                     mnemonic: "BCS",
                     addressing: "Relative",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDA_ind_y => {
                 &Instruction{
                     cycles: 5,
@@ -1392,9 +2493,34 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::lda_address),
                     mnemonic: "LDA",
                     addressing: "(IND), Y",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::KIL_imp_b2 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::LAX_ind_y => {
+                &Instruction{
+                    cycles: 5,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_y_extra,
+                    op_delegate: OpDelegate::Address(ops::lax_address),
+                    mnemonic: "LAX",
+                    addressing: "(IND), Y",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::LDY_zp_x => {
                 &Instruction{
                     cycles: 4,
@@ -1403,9 +2529,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ldy_address),
                     mnemonic: "LDY",
                     addressing: "ZP, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDA_zp_x => {
                 &Instruction{
                     cycles: 4,
@@ -1414,9 +2541,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::lda_address),
                     mnemonic: "LDA",
                     addressing: "ZP, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDX_zp_y => {
                 &Instruction{
                     cycles: 4,
@@ -1425,9 +2553,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ldx_address),
                     mnemonic: "LDX",
                     addressing: "ZP, Y",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::LAX_zp_y => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_y,
+                    op_delegate: OpDelegate::Address(ops::lax_address),
+                    mnemonic: "LAX",
+                    addressing: "ZP, Y",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::CLV_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1436,9 +2577,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::clv_implied),
                     mnemonic: "CLV",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDA_abs_y => {
                 &Instruction{
                     cycles: 4,
@@ -1447,9 +2589,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::lda_address),
                     mnemonic: "LDA",
                     addressing: "ABS, Y",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::TSX_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1458,9 +2601,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::tsx_implied),
                     mnemonic: "TSX",
                     addressing: "Implied",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::LAS_abs_y => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_y_extra,
+                    op_delegate: OpDelegate::Address(ops::las_address),
+                    mnemonic: "LAS",
+                    addressing: "ABS, Y",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::LDY_abs_x => {
                 &Instruction{
                     cycles: 4,
@@ -1469,9 +2625,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ldy_address),
                     mnemonic: "LDY",
                     addressing: "ABS, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDA_abs_x => {
                 &Instruction{
                     cycles: 4,
@@ -1480,9 +2637,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::lda_address),
                     mnemonic: "LDA",
                     addressing: "ABS, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::LDX_abs_y => {
                 &Instruction{
                     cycles: 4,
@@ -1491,9 +2649,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::ldx_address),
                     mnemonic: "LDX",
                     addressing: "ABS, Y",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::LAX_abs_y => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_y_extra,
+                    op_delegate: OpDelegate::Address(ops::lax_address),
+                    mnemonic: "LAX",
+                    addressing: "ABS, Y",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::CPY_imm => {
                 &Instruction{
                     cycles: 2,
@@ -1502,9 +2673,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Immediate(ops::cpy_immediate),
                     mnemonic: "CPY",
                     addressing: "IMM",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::CMP_ind_x => {
                 &Instruction{
                     cycles: 6,
@@ -1513,9 +2685,34 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::cmp_address),
                     mnemonic: "CMP",
                     addressing: "(IND, X)",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::NOP_imm_c2 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::nop_immediate),
+                    mnemonic: "NOP",
+                    addressing: "IMM",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::DCP_ind_x => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_x,
+                    op_delegate: OpDelegate::Address(ops::dcp_address),
+                    mnemonic: "DCP",
+                    addressing: "(IND, X)",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::CPY_zp => {
                 &Instruction{
                     cycles: 3,
@@ -1524,9 +2721,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::cpy_address),
                     mnemonic: "CPY",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::CMP_zp => {
                 &Instruction{
                     cycles: 3,
@@ -1535,9 +2733,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::cmp_address),
                     mnemonic: "CMP",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::DEC_zp => {
                 &Instruction{
                     cycles: 5,
@@ -1546,9 +2745,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::dec_address),
                     mnemonic: "DEC",
                     addressing: "ZP",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::DCP_zp => {
+                &Instruction{
+                    cycles: 5,
+                    bytes: 2,
+                    addr_delegate: addressing::zp,
+                    op_delegate: OpDelegate::Address(ops::dcp_address),
+                    mnemonic: "DCP",
+                    addressing: "ZP",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::INY_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1557,9 +2769,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::iny_implied),
                     mnemonic: "INY",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::CMP_imm => {
                 &Instruction{
                     cycles: 2,
@@ -1568,9 +2781,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Immediate(ops::cmp_immediate),
                     mnemonic: "CMP",
                     addressing: "IMM",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::DEX_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1579,9 +2793,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::dex_implied),
                     mnemonic: "DEX",
                     addressing: "Implied",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::AXS_imm => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::axs_immediate),
+                    mnemonic: "AXS",
+                    addressing: "IMM",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::CPY_abs => {
                 &Instruction{
                     cycles: 4,
@@ -1590,9 +2817,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::cpy_address),
                     mnemonic: "CPY",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::CMP_abs => {
                 &Instruction{
                     cycles: 4,
@@ -1601,9 +2829,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::cmp_address),
                     mnemonic: "CMP",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::DEC_abs => {
                 &Instruction{
                     cycles: 6,
@@ -1612,9 +2841,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::dec_address),
                     mnemonic: "DEC",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::DCP_abs => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 3,
+                    addr_delegate: addressing::abs,
+                    op_delegate: OpDelegate::Address(ops::dcp_address),
+                    mnemonic: "DCP",
+                    addressing: "Absolute",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::BNE_rel => {
                 &Instruction{
                     cycles: 2,
@@ -1623,9 +2865,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::bne_address),
                     mnemonic: "BNE",
                     addressing: "Relative",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::CMP_ind_y => {
                 &Instruction{
                     cycles: 5,
@@ -1634,9 +2877,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::cmp_address),
                     mnemonic: "CMP",
                     addressing: "(IND), Y",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::KIL_imp_d2 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::DCP_ind_y => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_y,
+                    op_delegate: OpDelegate::Address(ops::dcp_address),
+                    mnemonic: "DCP",
+                    addressing: "(IND), Y",
+                    unofficial: true,
                 }
             }
-            
+    
+            Opcode::NOP_zp_x_d4 => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ZP, X",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::CMP_zp_x => {
                 &Instruction{
                     cycles: 4,
@@ -1645,9 +2925,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::cmp_address),
                     mnemonic: "CMP",
                     addressing: "ZP, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::DEC_zp_x => {
                 &Instruction{
                     cycles: 6,
@@ -1656,9 +2937,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::dec_address),
                     mnemonic: "DEC",
                     addressing: "ZP, X",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::DCP_zp_x => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::dcp_address),
+                    mnemonic: "DCP",
+                    addressing: "ZP, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::CLD_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1667,9 +2961,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::cld_implied),
                     mnemonic: "CLD",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::CMP_abs_y => {
                 &Instruction{
                     cycles: 4,
@@ -1678,9 +2973,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::cmp_address),
                     mnemonic: "CMP",
                     addressing: "ABS, Y",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::NOP_imp_da => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::nop_implied),
+                    mnemonic: "NOP",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::DCP_abs_y => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_y,
+                    op_delegate: OpDelegate::Address(ops::dcp_address),
+                    mnemonic: "DCP",
+                    addressing: "ABS, Y",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_abs_x_dc => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x_extra,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ABS, X",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::CMP_abs_x => {
                 &Instruction{
                     cycles: 4,
@@ -1689,9 +3021,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::cmp_address),
                     mnemonic: "CMP",
                     addressing: "ABS, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::DEC_abs_x => {
                 &Instruction{
                     cycles: 7,
@@ -1700,9 +3033,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::dec_address),
                     mnemonic: "DEC",
                     addressing: "ABS, X",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::DCP_abs_x => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x,
+                    op_delegate: OpDelegate::Address(ops::dcp_address),
+                    mnemonic: "DCP",
+                    addressing: "ABS, X",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::CPX_imm => {
                 &Instruction{
                     cycles: 2,
@@ -1711,9 +3057,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Immediate(ops::cpx_immediate),
                     mnemonic: "CPX",
                     addressing: "IMM",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::SBC_ind_x => {
                 &Instruction{
                     cycles: 6,
@@ -1722,9 +3069,34 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sbc_address),
                     mnemonic: "SBC",
                     addressing: "(IND, X)",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::NOP_imm_e2 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::nop_immediate),
+                    mnemonic: "NOP",
+                    addressing: "IMM",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::ISC_ind_x => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_x,
+                    op_delegate: OpDelegate::Address(ops::isc_address),
+                    mnemonic: "ISC",
+                    addressing: "(IND, X)",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::CPX_zp => {
                 &Instruction{
                     cycles: 3,
@@ -1733,9 +3105,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::cpx_address),
                     mnemonic: "CPX",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::SBC_zp => {
                 &Instruction{
                     cycles: 3,
@@ -1744,9 +3117,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sbc_address),
                     mnemonic: "SBC",
                     addressing: "ZP",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::INC_zp => {
                 &Instruction{
                     cycles: 5,
@@ -1755,9 +3129,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::inc_address),
                     mnemonic: "INC",
                     addressing: "ZP",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::ISC_zp => {
+                &Instruction{
+                    cycles: 5,
+                    bytes: 2,
+                    addr_delegate: addressing::zp,
+                    op_delegate: OpDelegate::Address(ops::isc_address),
+                    mnemonic: "ISC",
+                    addressing: "ZP",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::INX_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1766,9 +3153,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::inx_implied),
                     mnemonic: "INX",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::SBC_imm => {
                 &Instruction{
                     cycles: 2,
@@ -1777,9 +3165,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Immediate(ops::sbc_immediate),
                     mnemonic: "SBC",
                     addressing: "IMM",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::NOP_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1788,9 +3177,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::nop_implied),
                     mnemonic: "NOP",
                     addressing: "Implied",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::SBC_imm_eb => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 2,
+                    addr_delegate: addressing::imm,
+                    op_delegate: OpDelegate::Immediate(ops::sbc_immediate),
+                    mnemonic: "SBC",
+                    addressing: "IMM",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::CPX_abs => {
                 &Instruction{
                     cycles: 4,
@@ -1799,9 +3201,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::cpx_address),
                     mnemonic: "CPX",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::SBC_abs => {
                 &Instruction{
                     cycles: 4,
@@ -1810,9 +3213,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sbc_address),
                     mnemonic: "SBC",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::INC_abs => {
                 &Instruction{
                     cycles: 6,
@@ -1821,9 +3225,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::inc_address),
                     mnemonic: "INC",
                     addressing: "Absolute",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::ISC_abs => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 3,
+                    addr_delegate: addressing::abs,
+                    op_delegate: OpDelegate::Address(ops::isc_address),
+                    mnemonic: "ISC",
+                    addressing: "Absolute",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::BEQ_rel => {
                 &Instruction{
                     cycles: 2,
@@ -1832,9 +3249,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::beq_address),
                     mnemonic: "BEQ",
                     addressing: "Relative",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::SBC_ind_y => {
                 &Instruction{
                     cycles: 5,
@@ -1843,9 +3261,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sbc_address),
                     mnemonic: "SBC",
                     addressing: "(IND), Y",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::KIL_imp_f2 => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::kil_implied),
+                    mnemonic: "KIL",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::ISC_ind_y => {
+                &Instruction{
+                    cycles: 8,
+                    bytes: 2,
+                    addr_delegate: addressing::ind_y,
+                    op_delegate: OpDelegate::Address(ops::isc_address),
+                    mnemonic: "ISC",
+                    addressing: "(IND), Y",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_zp_x_f4 => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ZP, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::SBC_zp_x => {
                 &Instruction{
                     cycles: 4,
@@ -1854,9 +3309,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sbc_address),
                     mnemonic: "SBC",
                     addressing: "ZP, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::INC_zp_x => {
                 &Instruction{
                     cycles: 6,
@@ -1865,9 +3321,22 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::inc_address),
                     mnemonic: "INC",
                     addressing: "ZP, X",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::ISC_zp_x => {
+                &Instruction{
+                    cycles: 6,
+                    bytes: 2,
+                    addr_delegate: addressing::zp_x,
+                    op_delegate: OpDelegate::Address(ops::isc_address),
+                    mnemonic: "ISC",
+                    addressing: "ZP, X",
+                    unofficial: true,
                 }
             }
-            
+    
             Opcode::SED_imp => {
                 &Instruction{
                     cycles: 2,
@@ -1876,9 +3345,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Implied(ops::sed_implied),
                     mnemonic: "SED",
                     addressing: "Implied",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::SBC_abs_y => {
                 &Instruction{
                     cycles: 4,
@@ -1887,9 +3357,46 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sbc_address),
                     mnemonic: "SBC",
                     addressing: "ABS, Y",
+                    unofficial: false,
                 }
             }
-            
+    
+            Opcode::NOP_imp_fa => {
+                &Instruction{
+                    cycles: 2,
+                    bytes: 1,
+                    addr_delegate: addressing::imp,
+                    op_delegate: OpDelegate::Implied(ops::nop_implied),
+                    mnemonic: "NOP",
+                    addressing: "Implied",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::ISC_abs_y => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_y,
+                    op_delegate: OpDelegate::Address(ops::isc_address),
+                    mnemonic: "ISC",
+                    addressing: "ABS, Y",
+                    unofficial: true,
+                }
+            }
+    
+            Opcode::NOP_abs_x_fc => {
+                &Instruction{
+                    cycles: 4,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x_extra,
+                    op_delegate: OpDelegate::Address(ops::nop_address),
+                    mnemonic: "NOP",
+                    addressing: "ABS, X",
+                    unofficial: true,
+                }
+            }
+    
             Opcode::SBC_abs_x => {
                 &Instruction{
                     cycles: 4,
@@ -1898,9 +3405,10 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::sbc_address),
                     mnemonic: "SBC",
                     addressing: "ABS, X",
+                    unofficial: false,
                 }
             }
-            
+    
             Opcode::INC_abs_x => {
                 &Instruction{
                     cycles: 7,
@@ -1909,6 +3417,19 @@ impl Opcode {
                     op_delegate: OpDelegate::Address(ops::inc_address),
                     mnemonic: "INC",
                     addressing: "ABS, X",
+                    unofficial: false,
+                }
+            }
+    
+            Opcode::ISC_abs_x => {
+                &Instruction{
+                    cycles: 7,
+                    bytes: 3,
+                    addr_delegate: addressing::abs_x,
+                    op_delegate: OpDelegate::Address(ops::isc_address),
+                    mnemonic: "ISC",
+                    addressing: "ABS, X",
+                    unofficial: true,
                 }
             }
         }
@@ -22,40 +22,62 @@ pub fn zp(s: &mut CpuState, _cycle: u8) -> AddrDelegateReturn {
 
 pub fn zp_x(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
     match cycle {
-        1 => AddrDelegateReturn::Yield(BusMessage::Nop),
+        // dummy read of the unindexed address while the index is added
+        1 => AddrDelegateReturn::Yield(BusMessage::Read{addr: s.o1 as u16}),
         2 => {
-            s.addr = (s.o1 + s.regs.x) as u16;
+            // the effective address wraps around within the zero page
+            s.addr = s.o1.wrapping_add(s.regs.x) as u16;
             AddrDelegateReturn::Return(Operand::Address(s.addr))
         }
-        _ => panic!("Addressing cannot continue after Return")
+        _ => AddrDelegateReturn::Overrun
     }
 }
 
 pub fn zp_y(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
     match cycle {
-        1 => AddrDelegateReturn::Yield(BusMessage::Nop),
+        // dummy read of the unindexed address while the index is added
+        1 => AddrDelegateReturn::Yield(BusMessage::Read{addr: s.o1 as u16}),
         2 => {
-            s.addr = (s.o1 + s.regs.y) as u16;
+            // the effective address wraps around within the zero page
+            s.addr = s.o1.wrapping_add(s.regs.y) as u16;
             AddrDelegateReturn::Return(Operand::Address(s.addr))
         }
-        _ => panic!("Addressing cannot continue after Return")
+        _ => AddrDelegateReturn::Overrun
     }
 }
 
 pub fn abs_x(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
-    s.addr = s.o1 as u16;
-    s.addr |= (s.o2 as u16) << 8;
-    s.addr += s.regs.x as u16;
+    match cycle {
+        1 => {
+            s.addr = s.o1 as u16;
+            s.addr |= (s.o2 as u16) << 8;
+            s.addr = s.addr.wrapping_add(s.regs.x as u16);
 
-    AddrDelegateReturn::Return(Operand::Address(s.addr))
+            // stores and read-modify-write instructions always take the extra cycle,
+            // reading from the address before the carry into the high byte is fixed
+            let uncarried = (s.o2 as u16) << 8 | (s.addr & 0xFF);
+            AddrDelegateReturn::Yield(BusMessage::Read{addr: uncarried})
+        }
+        2 => AddrDelegateReturn::Return(Operand::Address(s.addr)),
+        _ => AddrDelegateReturn::Overrun
+    }
 }
 
 pub fn abs_y(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
-    s.addr = s.o1 as u16;
-    s.addr |= (s.o2 as u16) << 8;
-    s.addr += s.regs.y as u16;
+    match cycle {
+        1 => {
+            s.addr = s.o1 as u16;
+            s.addr |= (s.o2 as u16) << 8;
+            s.addr = s.addr.wrapping_add(s.regs.y as u16);
 
-    AddrDelegateReturn::Return(Operand::Address(s.addr))
+            // stores and read-modify-write instructions always take the extra cycle,
+            // reading from the address before the carry into the high byte is fixed
+            let uncarried = (s.o2 as u16) << 8 | (s.addr & 0xFF);
+            AddrDelegateReturn::Yield(BusMessage::Read{addr: uncarried})
+        }
+        2 => AddrDelegateReturn::Return(Operand::Address(s.addr)),
+        _ => AddrDelegateReturn::Overrun
+    }
 }
 
 pub fn imp(_s: &mut CpuState, _cycle: u8) -> AddrDelegateReturn {
@@ -73,26 +95,30 @@ pub fn rel(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
 pub fn ind_x(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
     match cycle {
         1 => {
+            // dummy read of the zero page address while X is added
+            AddrDelegateReturn::Yield(BusMessage::Read{addr: s.o1 as u16})
+        }
+        2 => {
             // fetch zero page address
-            let jmpaddr = (s.regs.x + s.o1) as u16;
+            let jmpaddr = s.o1.wrapping_add(s.regs.x) as u16;
             AddrDelegateReturn::Yield(BusMessage::Read{addr: jmpaddr})
         }
-        2 => {
+        3 => {
             // set new addr LSB
             s.addr = s.data as u16;
 
-            // fetch new PC MSB at operand+1
-            let jmpaddr = (s.regs.x + s.o1 + 1) as u16;
+            // fetch new PC MSB at operand+1, wrapping around within the zero page
+            let jmpaddr = s.o1.wrapping_add(s.regs.x).wrapping_add(1) as u16;
             AddrDelegateReturn::Yield(BusMessage::Read{addr: jmpaddr})
         }
-        3 => {
+        4 => {
             // set new addr MSB
             s.addr |= (s.data as u16) << 8;
 
             // done
             AddrDelegateReturn::Return(Operand::Address(s.addr))
         }
-        _ => panic!("Addressing cannot continue after Return")
+        _ => AddrDelegateReturn::Overrun
     }
 }
 
@@ -107,19 +133,24 @@ pub fn ind_y(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
             s.addr = s.data as u16;
 
             // fetch MSB of address from zero page
-            AddrDelegateReturn::Yield(BusMessage::Read{addr: (s.o1 + 1) as u16})
+            AddrDelegateReturn::Yield(BusMessage::Read{addr: s.o1.wrapping_add(1) as u16})
         }
         3 => {
             // set new PC MSB
             s.addr |= (s.data as u16) << 8;
-            
-            // add Y
-            s.addr += s.regs.y as u16;
 
+            // add Y, stores and read-modify-write instructions always take the extra cycle,
+            // reading from the address before the carry into the high byte is fixed
+            let uncarried = (s.addr & 0xFF00) | (s.addr.wrapping_add(s.regs.y as u16) & 0xFF);
+            s.addr = s.addr.wrapping_add(s.regs.y as u16);
+
+            AddrDelegateReturn::Yield(BusMessage::Read{addr: uncarried})
+        }
+        4 => {
             // done
             AddrDelegateReturn::Return(Operand::Address(s.addr))
         }
-        _ => panic!("Addressing cannot continue after Return")
+        _ => AddrDelegateReturn::Overrun
     }
 }
 
@@ -135,10 +166,10 @@ pub fn ind(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
             // set new PC LSB
             s.addr = s.data as u16;
 
-            // fetch new PC MSB at operand+1
-            let mut jmpaddr = s.o1 as u16;
+            // fetch new PC MSB at operand+1, the 6502 does not carry into the high byte of the pointer
+            let mut jmpaddr = s.o1.wrapping_add(1) as u16;
             jmpaddr |= (s.o2 as u16) << 8;
-            AddrDelegateReturn::Yield(BusMessage::Read{addr: jmpaddr+1})
+            AddrDelegateReturn::Yield(BusMessage::Read{addr: jmpaddr})
         }
         3 => {
             // set new PC MSB
@@ -147,7 +178,45 @@ pub fn ind(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
             // done
             AddrDelegateReturn::Return(Operand::Address(s.addr))
         }
-        _ => panic!("Addressing cannot continue after Return")
+        _ => AddrDelegateReturn::Overrun
+    }
+}
+
+// JSR only fetches the low byte of its target before pushing the return address,
+// the high byte is read last so the pushed address points to it
+pub fn abs_jsr(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
+    match cycle {
+        1 => {
+            // dummy read of the stack while the low byte is buffered
+            AddrDelegateReturn::Yield(BusMessage::Read{addr: 0x100 | s.regs.sp as u16})
+        }
+        2 => {
+            // push PC high byte to stack
+            let data = (s.regs.pc >> 8) as u8;
+            let sp = s.regs.sp;
+            s.regs.sp = s.regs.sp.wrapping_sub(1);
+            AddrDelegateReturn::Yield(BusMessage::Write{addr: 0x100 | sp as u16, data})
+        }
+        3 => {
+            // push PC low byte to stack
+            let data = s.regs.pc as u8;
+            let sp = s.regs.sp;
+            s.regs.sp = s.regs.sp.wrapping_sub(1);
+            AddrDelegateReturn::Yield(BusMessage::Write{addr: 0x100 | sp as u16, data})
+        }
+        4 => {
+            // fetch high byte of the target
+            AddrDelegateReturn::Yield(BusMessage::Read{addr: s.regs.pc})
+        }
+        5 => {
+            s.o2 = s.data;
+
+            s.addr = s.o1 as u16;
+            s.addr |= (s.o2 as u16) << 8;
+
+            AddrDelegateReturn::Return(Operand::Address(s.addr))
+        }
+        _ => AddrDelegateReturn::Overrun
     }
 }
 
@@ -156,12 +225,14 @@ pub fn abs_x_extra(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
         1 => {
             s.addr = s.o1 as u16;
             s.addr |= (s.o2 as u16) << 8;
-            s.addr += s.regs.x as u16;
-            
-            // this instruction takes one additional cycle when the address crosses a page boundary
-            if s.addr >> 8 != s.o2 as u16{
+            s.addr = s.addr.wrapping_add(s.regs.x as u16);
+
+            // this instruction takes one additional cycle when the address crosses a page boundary,
+            // reading from the address before the carry into the high byte is fixed
+            if s.addr >> 8 != s.o2 as u16 {
                 s.extra_cycle = true;
-                AddrDelegateReturn::Yield(BusMessage::Nop)
+                let uncarried = (s.o2 as u16) << 8 | (s.addr & 0xFF);
+                AddrDelegateReturn::Yield(BusMessage::Read{addr: uncarried})
             } else {
                 AddrDelegateReturn::Return(Operand::Address(s.addr))
             }
@@ -169,7 +240,7 @@ pub fn abs_x_extra(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
         2 => {
             AddrDelegateReturn::Return(Operand::Address(s.addr))
         }
-        _ => panic!("Addressing cannot continue after Return")
+        _ => AddrDelegateReturn::Overrun
     }
 }
 
@@ -178,12 +249,14 @@ pub fn abs_y_extra(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
         1 => {
             s.addr = s.o1 as u16;
             s.addr |= (s.o2 as u16) << 8;
-            s.addr += s.regs.y as u16;
-            
-            // this instruction takes one additional cycle when the address crosses a page boundary
-            if s.addr >> 8 != s.o2 as u16{
+            s.addr = s.addr.wrapping_add(s.regs.y as u16);
+
+            // this instruction takes one additional cycle when the address crosses a page boundary,
+            // reading from the address before the carry into the high byte is fixed
+            if s.addr >> 8 != s.o2 as u16 {
                 s.extra_cycle = true;
-                AddrDelegateReturn::Yield(BusMessage::Nop)
+                let uncarried = (s.o2 as u16) << 8 | (s.addr & 0xFF);
+                AddrDelegateReturn::Yield(BusMessage::Read{addr: uncarried})
             } else {
                 AddrDelegateReturn::Return(Operand::Address(s.addr))
             }
@@ -191,7 +264,7 @@ pub fn abs_y_extra(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
         2 => {
             AddrDelegateReturn::Return(Operand::Address(s.addr))
         }
-        _ => panic!("Addressing cannot continue after Return")
+        _ => AddrDelegateReturn::Overrun
     }
 }
 
@@ -206,23 +279,31 @@ pub fn ind_y_extra(s: &mut CpuState, cycle: u8) -> AddrDelegateReturn {
             s.addr = s.data as u16;
 
             // fetch MSB of address from zero page
-            AddrDelegateReturn::Yield(BusMessage::Read{addr: (s.o1 + 1) as u16})
+            AddrDelegateReturn::Yield(BusMessage::Read{addr: s.o1.wrapping_add(1) as u16})
         }
         3 => {
-            // instructions needs an extra cycle if page boundary is crossed
-            if s.addr >> 8 != s.data as u16 {
-                s.extra_cycle = true;
-            }
-
             // set new PC MSB
             s.addr |= (s.data as u16) << 8;
-            
+
             // add Y
-            s.addr += s.regs.y as u16;
+            let base = s.addr;
+            s.addr = s.addr.wrapping_add(s.regs.y as u16);
 
+            // instructions needs an extra cycle if page boundary is crossed,
+            // reading from the address before the carry into the high byte is fixed
+            if s.addr >> 8 != base >> 8 {
+                s.extra_cycle = true;
+                let uncarried = (base & 0xFF00) | (s.addr & 0xFF);
+                AddrDelegateReturn::Yield(BusMessage::Read{addr: uncarried})
+            } else {
+                // done
+                AddrDelegateReturn::Return(Operand::Address(s.addr))
+            }
+        }
+        4 => {
             // done
             AddrDelegateReturn::Return(Operand::Address(s.addr))
         }
-        _ => panic!("Addressing cannot continue after Return")
+        _ => AddrDelegateReturn::Overrun
     }
 }
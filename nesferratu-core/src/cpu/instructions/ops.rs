@@ -3,19 +3,31 @@ use crate::BusMessage;
 use crate::BusMessage::*;
 
 pub fn txs_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.sp = s.regs.x;
-    Nop
+    match cycle {
+        1 => {
+            s.regs.sp = s.regs.x;
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn cld_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.set_flag(CpuFlags::D, false);
-    Nop
+    match cycle {
+        1 => {
+            s.regs.set_flag(CpuFlags::D, false);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn asl_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
     match cycle {
         1 => Read{addr: address}, // fetch value
         2 => {
+            let unmodified = s.data;
+
             // carry flag contains old LSB
             s.regs.set_flag(CpuFlags::C, s.data & 0x80 == 0x80); // work with fetched value
 
@@ -25,17 +37,25 @@ pub fn asl_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             s.regs.set_flag(CpuFlags::Z, s.data == 0);
         
             // negative flag
-            s.regs.set_flag(CpuFlags::Z, s.data & 0x80 == 0x80);
+            s.regs.set_flag(CpuFlags::N, s.data & 0x80 == 0x80);
         
-            Write{addr: address, data: s.data} // write back changed value
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
         }
+        3 => Write{addr: address, data: s.data}, // write back changed value
         _ => Nop
     }
 }
 
 pub fn tay_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.y = s.regs.a;
-    Nop
+    match cycle {
+        1 => {
+            s.regs.y = s.regs.a;
+            s.regs.set_flag(CpuFlags::Z, s.regs.y == 0);
+            s.regs.set_flag(CpuFlags::N, s.regs.y & 0x80 == 0x80);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn sbc_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
@@ -59,6 +79,9 @@ pub fn sbc_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             // see http://www.righto.com/2012/12/the-6502-overflow-flag-explained.html
             s.regs.set_flag(CpuFlags::V, (s.regs.a as usize ^ result) & (immediate as usize ^ result) & 0x80 > 1);
         
+            // negative flag
+            s.regs.set_flag(CpuFlags::N, result & 0x80 == 0x80);
+
             // load result into accumultoar
             s.regs.a = result as u8;
         
@@ -69,19 +92,9 @@ pub fn sbc_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
 }
 
 pub fn jsr_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
-    match cycle {
-        1 => Write{addr: 0x100 | s.regs.sp as u16, data: ((s.regs.pc-1) >> 8) as u8}, // push PC high byte to stack
-        2 => {
-            s.regs.sp = s.regs.sp.wrapping_sub(1);
-            Write{addr: 0x100 | s.regs.sp as u16, data: (s.regs.pc-1) as u8} // push PC low byte to stack
-        }
-        3 => {
-            s.regs.sp = s.regs.sp.wrapping_sub(1);
-            s.regs.pc = address; // jump to new PC
-            Nop
-        }
-        _ => Nop,
-    }
+    // the return address has already been pushed during addressing
+    s.regs.pc = address; // jump to new PC
+    Nop
 }
 
 pub fn lda_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage {
@@ -94,13 +107,13 @@ pub fn lda_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage
 pub fn cpy_immediate(s: &mut CpuState, immediate: u8, cycle: u8) -> BusMessage {
     match cycle {
         1 => {
-            let result = s.regs.y.wrapping_sub(s.data); // CPY performs Y - M and sets flags
+            let result = s.regs.y.wrapping_sub(immediate); // CPY performs Y - M and sets flags
 
             // zero flag <- A == M
             s.regs.set_flag(CpuFlags::Z, result == 0);
 
             // carry flag <- A >= M
-            s.regs.set_flag(CpuFlags::C, s.regs.y >= s.data);
+            s.regs.set_flag(CpuFlags::C, s.regs.y >= immediate);
 
             // negative flag
             s.regs.set_flag(CpuFlags::N, result & 0x80 == 0x80);
@@ -113,30 +126,38 @@ pub fn cpy_immediate(s: &mut CpuState, immediate: u8, cycle: u8) -> BusMessage {
 
 pub fn pha_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
     match cycle {
-        1 => Write{addr: 0x100 | s.regs.sp as u16, data: s.regs.a},
+        1 => Read{addr: s.regs.pc}, // dummy read of the next byte
         2 => {
+            let sp = s.regs.sp;
             s.regs.sp = s.regs.sp.wrapping_sub(1); // decrement stack pointer
-            Nop
+            Write{addr: 0x100 | sp as u16, data: s.regs.a}
         }
         _ => Nop
     }
 }
 
 pub fn sei_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.set_flag(CpuFlags::I, true);
-    Nop
+    match cycle {
+        1 => {
+            s.regs.set_flag(CpuFlags::I, true);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn inc_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
     match cycle {
         1 => Read{addr: address},
         2 => {
+            let unmodified = s.data;
+
             s.data = s.data.wrapping_add(1);
             s.regs.set_flag(CpuFlags::Z, s.data == 0);
             s.regs.set_flag(CpuFlags::N, s.data & 0x80 == 0x80);
-            Nop
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
         }
-        3 => Write{addr: address, data: s.data},
+        3 => Write{addr: address, data: s.data}, // write back changed value
         _ => Nop,
     }
 }
@@ -200,9 +221,9 @@ pub fn rol_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
             s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
 
             // negative flag
-            s.regs.set_flag(CpuFlags::Z, s.regs.a & 0x80 == 0x80);
+            s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
 
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next byte
         }
         _ => Nop
     }
@@ -212,12 +233,14 @@ pub fn dec_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
     match cycle {
         1 => Read{addr: address},
         2 => {
+            let unmodified = s.data;
+
             s.data = s.data.wrapping_sub(1);
             s.regs.set_flag(CpuFlags::Z, s.data == 0);
             s.regs.set_flag(CpuFlags::N, s.data & 0x80 == 0x80);
-            Nop
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
         }
-        3 => Write{addr: address, data: s.data},
+        3 => Write{addr: address, data: s.data}, // write back changed value
         _ => Nop,
     }
 }
@@ -230,37 +253,56 @@ pub fn ldx_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage
 }
 
 pub fn tsx_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.x = s.regs.sp;
-    Nop
+    match cycle {
+        1 => {
+            s.regs.x = s.regs.sp;
+            s.regs.set_flag(CpuFlags::Z, s.regs.x == 0);
+            s.regs.set_flag(CpuFlags::N, s.regs.x & 0x80 == 0x80);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn inx_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.x = s.regs.x.wrapping_add(1);
-    s.regs.set_flag(CpuFlags::Z, s.regs.x == 0);
-    s.regs.set_flag(CpuFlags::N, s.regs.x & 0x80 == 0x80);
-    Nop
+    match cycle {
+        1 => {
+            s.regs.x = s.regs.x.wrapping_add(1);
+            s.regs.set_flag(CpuFlags::Z, s.regs.x == 0);
+            s.regs.set_flag(CpuFlags::N, s.regs.x & 0x80 == 0x80);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn brk_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
     match cycle {
-        1 => Write{addr: 0x100 | s.regs.sp as u16, data: ((s.regs.pc+1) >> 8) as u8}, // push BRK PC+2 high byte to stack
-        2 => {
-            s.regs.sp = s.regs.sp.wrapping_sub(1);
-            Write{addr: 0x100 | s.regs.sp as u16, data: (s.regs.pc+1) as u8} // push BRK PC+2 low byte to stack
+        1 => {
+            // BRK skips the padding byte after the opcode
+            let pc = s.regs.pc;
+            s.regs.pc = s.regs.pc.wrapping_add(1);
+            Read{addr: pc}
         }
+        2 => Write{addr: 0x100 | s.regs.sp as u16, data: (s.regs.pc >> 8) as u8}, // push BRK PC+2 high byte to stack
         3 => {
             s.regs.sp = s.regs.sp.wrapping_sub(1);
-            Write{addr: 0x100 | s.regs.sp as u16, data: s.regs.status | 0x10} // push status register with B set to stack
+            Write{addr: 0x100 | s.regs.sp as u16, data: s.regs.pc as u8} // push BRK PC+2 low byte to stack
         }
         4 => {
             s.regs.sp = s.regs.sp.wrapping_sub(1);
-            Read{addr: 0xFFFE} // fetch PC low byte from vector
+            Write{addr: 0x100 | s.regs.sp as u16, data: s.regs.status | CpuFlags::B as u8 | 0x20} // push status register with B and bit 5 set to stack
         }
         5 => {
+            s.regs.sp = s.regs.sp.wrapping_sub(1);
+            Read{addr: 0xFFFE} // fetch PC low byte from vector
+        }
+        6 => {
             s.regs.pc = s.data as u16; // set PC low byte from stack
+            s.regs.set_flag(CpuFlags::I, true); // set interrupt disable flag
             Read{addr: 0xFFFF} // fetch PC high byte from vector
         }
-        6 => {
+        7 => {
             s.regs.pc |= (s.data as u16) << 8; // set PC high byte from stack
             Nop
         }
@@ -269,15 +311,25 @@ pub fn brk_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
 }
 
 pub fn iny_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.y = s.regs.y.wrapping_add(1);
-    s.regs.set_flag(CpuFlags::Z, s.regs.y == 0);
-    s.regs.set_flag(CpuFlags::N, s.regs.y & 0x80 == 0x80);
-    Nop
+    match cycle {
+        1 => {
+            s.regs.y = s.regs.y.wrapping_add(1);
+            s.regs.set_flag(CpuFlags::Z, s.regs.y == 0);
+            s.regs.set_flag(CpuFlags::N, s.regs.y & 0x80 == 0x80);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn sed_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.set_flag(CpuFlags::D, true);
-    Nop
+    match cycle {
+        1 => {
+            s.regs.set_flag(CpuFlags::D, true);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn sbc_immediate(s: &mut CpuState, mut immediate: u8, _cycle: u8) -> BusMessage {
@@ -298,6 +350,9 @@ pub fn sbc_immediate(s: &mut CpuState, mut immediate: u8, _cycle: u8) -> BusMess
     // see http://www.righto.com/2012/12/the-6502-overflow-flag-explained.html
     s.regs.set_flag(CpuFlags::V, (s.regs.a as usize ^ result) & (immediate as usize ^ result) & 0x80 > 1);
 
+    // negative flag
+    s.regs.set_flag(CpuFlags::N, result & 0x80 == 0x80);
+
     // load result into accumultoar
     s.regs.a = result as u8;
 
@@ -314,7 +369,7 @@ pub fn ora_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
         
             // negative flag
-            s.regs.set_flag(CpuFlags::Z, s.regs.a & 0x80 == 0x80);
+            s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
         
             Nop
         }
@@ -323,8 +378,13 @@ pub fn ora_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
 }
 
 pub fn sec_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.set_flag(CpuFlags::C, true);
-    Nop
+    match cycle {
+        1 => {
+            s.regs.set_flag(CpuFlags::C, true);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn bne_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
@@ -333,17 +393,19 @@ pub fn bne_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             if !s.regs.get_flag(CpuFlags::Z) { // zero flag not set
                 s.extra_cycle = true;
             }
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next opcode
         }
         2 => { // can only be reached if branch condition met
-            // branch ops need one extra cycle if the branch jumps across a page boundary
+            // branch ops need one extra cycle if the branch jumps across a page boundary,
+            // reading from the target before the carry into the high byte is fixed
+            let uncarried = (s.regs.pc & 0xFF00) | (address & 0xFF);
             if s.regs.pc >> 8 != address >> 8 {
                 s.extra_cycle = true;
             }
 
             s.regs.pc = address;
-            
-            Nop
+
+            Read{addr: uncarried}
         }
         _ => Nop,
     }
@@ -375,6 +437,8 @@ pub fn rol_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
     match cycle {
         1 => Read{addr: address}, // fetch value
         2 => {
+            let unmodified = s.data;
+
             // save status register
             let temp = s.regs.status;
             
@@ -392,25 +456,32 @@ pub fn rol_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             // negative flag
             s.regs.set_flag(CpuFlags::N, s.data & 0x80 == 0x80);
         
-            Write{addr: address, data: s.data} // write back changed value
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
         }
+        3 => Write{addr: address, data: s.data}, // write back changed value
         _ => Nop
     }
 }
 
 pub fn dex_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.x = s.regs.x.wrapping_sub(1);
-    s.regs.set_flag(CpuFlags::Z, s.regs.x == 0);
-    s.regs.set_flag(CpuFlags::N, s.regs.x & 0x80 == 0x80);
-    Nop
+    match cycle {
+        1 => {
+            s.regs.x = s.regs.x.wrapping_sub(1);
+            s.regs.set_flag(CpuFlags::Z, s.regs.x == 0);
+            s.regs.set_flag(CpuFlags::N, s.regs.x & 0x80 == 0x80);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn php_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
     match cycle {
-        1 => Write{addr: 0x100 | s.regs.sp as u16, data: s.regs.status},
+        1 => Read{addr: s.regs.pc}, // dummy read of the next byte
         2 => {
+            let sp = s.regs.sp;
             s.regs.sp = s.regs.sp.wrapping_sub(1); // decrement stack pointer
-            Nop
+            Write{addr: 0x100 | sp as u16, data: s.regs.status | CpuFlags::B as u8 | 0x20} // push status register with B and bit 5 set
         }
         _ => Nop
     }
@@ -418,23 +489,26 @@ pub fn php_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
 
 pub fn rti_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
     match cycle {
-        1 => {
+        1 => Read{addr: s.regs.pc}, // dummy read of the next byte
+        2 => Read{addr: 0x100 | s.regs.sp as u16}, // dummy read while the stack pointer is incremented
+        3 => {
             s.regs.sp = s.regs.sp.wrapping_add(1); // increment stack pointer
             Read{addr: 0x100 | s.regs.sp as u16} // pull status register from stack
         }
-        2 => {
-            s.regs.status = s.data; // set status register from stack
+        4 => {
+            // set status register from stack, the B flag only exists on the stack and bit 5 is always high
+            s.regs.status = (s.data & !(CpuFlags::B as u8)) | 0x20;
 
             s.regs.sp = s.regs.sp.wrapping_add(1); // increment stack pointer
             Read{addr: 0x100 | s.regs.sp as u16} // pull PC low byte from stack
         }
-        3 => {
+        5 => {
             s.regs.pc = s.data as u16; // set PC low byte from stack
 
             s.regs.sp = s.regs.sp.wrapping_add(1); // increment stack pointer
             Read{addr: 0x100 | s.regs.sp as u16} // pull PC high byte from stack
         }
-        4 => {
+        6 => {
             s.regs.pc |= (s.data as u16) << 8; // set PC high byte from stack
             Nop
         }
@@ -454,9 +528,9 @@ pub fn asl_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
             s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
 
             // negative flag
-            s.regs.set_flag(CpuFlags::Z, s.regs.a & 0x80 == 0x80);
+            s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
 
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next byte
         }
         _ => Nop
     }
@@ -476,12 +550,20 @@ pub fn ldx_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
 }
 
 pub fn clv_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.set_flag(CpuFlags::V, false);
-    Nop
+    match cycle {
+        1 => {
+            s.regs.set_flag(CpuFlags::V, false);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn nop_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    Nop
+    match cycle {
+        1 => Read{addr: s.regs.pc}, // dummy read of the next byte
+        _ => Nop
+    }
 }
 
 pub fn adc_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage {
@@ -499,6 +581,9 @@ pub fn adc_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage
     // see http://www.righto.com/2012/12/the-6502-overflow-flag-explained.html
     s.regs.set_flag(CpuFlags::V, (s.regs.a as usize ^ result) & (immediate as usize ^ result) & 0x80 > 1);
 
+    // negative flag
+    s.regs.set_flag(CpuFlags::N, result & 0x80 == 0x80);
+
     // load result into accumultoar
     s.regs.a = result as u8;
 
@@ -506,8 +591,13 @@ pub fn adc_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage
 }
 
 pub fn cli_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.set_flag(CpuFlags::I, false);
-    Nop
+    match cycle {
+        1 => {
+            s.regs.set_flag(CpuFlags::I, false);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn stx_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
@@ -525,17 +615,19 @@ pub fn bmi_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             if s.regs.get_flag(CpuFlags::N) { // negative flag set
                 s.extra_cycle = true;
             }
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next opcode
         }
         2 => { // can only be reached if branch condition met
-            // branch ops need one extra cycle if the branch jumps across a page boundary
+            // branch ops need one extra cycle if the branch jumps across a page boundary,
+            // reading from the target before the carry into the high byte is fixed
+            let uncarried = (s.regs.pc & 0xFF00) | (address & 0xFF);
             if s.regs.pc >> 8 != address >> 8 {
                 s.extra_cycle = true;
             }
 
             s.regs.pc = address;
-            
-            Nop
+
+            Read{addr: uncarried}
         }
         _ => Nop,
     }
@@ -549,15 +641,27 @@ pub fn ldy_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage
 }
 
 pub fn tax_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.x = s.regs.a;
-    Nop
+    match cycle {
+        1 => {
+            s.regs.x = s.regs.a;
+            s.regs.set_flag(CpuFlags::Z, s.regs.x == 0);
+            s.regs.set_flag(CpuFlags::N, s.regs.x & 0x80 == 0x80);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn dey_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.y = s.regs.y.wrapping_sub(1);
-    s.regs.set_flag(CpuFlags::Z, s.regs.y == 0);
-    s.regs.set_flag(CpuFlags::N, s.regs.y & 0x80 == 0x80);
-    Nop
+    match cycle {
+        1 => {
+            s.regs.y = s.regs.y.wrapping_sub(1);
+            s.regs.set_flag(CpuFlags::Z, s.regs.y == 0);
+            s.regs.set_flag(CpuFlags::N, s.regs.y & 0x80 == 0x80);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn eor_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
@@ -570,7 +674,7 @@ pub fn eor_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
         
             // negative flag
-            s.regs.set_flag(CpuFlags::Z, s.regs.a & 0x80 == 0x80);
+            s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
         
             Nop
         }
@@ -590,9 +694,9 @@ pub fn lsr_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
             s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
 
             // negative flag
-            s.regs.set_flag(CpuFlags::Z, s.regs.a & 0x80 == 0x80);
+            s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
 
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next byte
         }
         _ => Nop
     }
@@ -604,17 +708,19 @@ pub fn bvs_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             if s.regs.get_flag(CpuFlags::V) { // overflow flag set
                 s.extra_cycle = true;
             }
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next opcode
         }
         2 => { // can only be reached if branch condition met
-            // branch ops need one extra cycle if the branch jumps across a page boundary
+            // branch ops need one extra cycle if the branch jumps across a page boundary,
+            // reading from the target before the carry into the high byte is fixed
+            let uncarried = (s.regs.pc & 0xFF00) | (address & 0xFF);
             if s.regs.pc >> 8 != address >> 8 {
                 s.extra_cycle = true;
             }
 
             s.regs.pc = address;
-            
-            Nop
+
+            Read{addr: uncarried}
         }
         _ => Nop,
     }
@@ -622,21 +728,23 @@ pub fn bvs_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
 
 pub fn rts_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
     match cycle {
-        1 => {
+        1 => Read{addr: s.regs.pc}, // dummy read of the next byte
+        2 => Read{addr: 0x100 | s.regs.sp as u16}, // dummy read while the stack pointer is incremented
+        3 => {
             s.regs.sp = s.regs.sp.wrapping_add(1); // increment stack pointer
             Read{addr: 0x100 | s.regs.sp as u16} // load new PC low
         }
-        2 => {
+        4 => {
             s.regs.pc = s.data as u16;
             s.regs.sp = s.regs.sp.wrapping_add(1); // increment stack pointer
             Read{addr: 0x100 | s.regs.sp as u16} // load new PC high
         }
-        3 => {
+        5 => {
             s.regs.pc |= (s.data as u16) << 8;
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the last byte of the JSR instruction
         }
-        4 => {
-            s.regs.pc += 1; // increment PC by one to point to next opcode
+        6 => {
+            s.regs.pc = s.regs.pc.wrapping_add(1); // increment PC by one to point to next opcode
             Nop
         }
         _ => Nop,
@@ -644,18 +752,28 @@ pub fn rts_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
 }
 
 pub fn tya_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.a = s.regs.y;
-    Nop
+    match cycle {
+        1 => {
+            s.regs.a = s.regs.y;
+            s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
+            s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn plp_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
     match cycle {
-        1 => {
+        1 => Read{addr: s.regs.pc}, // dummy read of the next byte
+        2 => Read{addr: 0x100 | s.regs.sp as u16}, // dummy read while the stack pointer is incremented
+        3 => {
             s.regs.sp = s.regs.sp.wrapping_add(1); // increment stack pointer
             Read{addr: 0x100 | s.regs.sp as u16}
         }
-        2 => {
-            s.regs.status = s.data;
+        4 => {
+            // the B flag only exists on the stack, bit 5 is always high
+            s.regs.status = (s.data & !(CpuFlags::B as u8)) | 0x20;
             Nop
         }
         _ => Nop
@@ -669,7 +787,7 @@ pub fn and_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage
     s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
 
     // negative flag
-    s.regs.set_flag(CpuFlags::Z, s.regs.a & 0x80 == 0x80);
+    s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
 
     Nop
 }
@@ -683,6 +801,8 @@ pub fn ror_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
     match cycle {
         1 => Read{addr: address}, // fetch value
         2 => {
+            let unmodified = s.data;
+
             // save status register
             let temp = s.regs.status;
             
@@ -698,10 +818,11 @@ pub fn ror_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             s.regs.set_flag(CpuFlags::Z, s.data == 0);
         
             // negative flag
-            s.regs.set_flag(CpuFlags::Z, s.data & 0x80 == 0x80);
+            s.regs.set_flag(CpuFlags::N, s.data & 0x80 == 0x80);
         
-            Write{addr: address, data: s.data} // write back changed value
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
         }
+        3 => Write{addr: address, data: s.data}, // write back changed value
         _ => Nop
     }
 }
@@ -742,17 +863,19 @@ pub fn beq_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             if s.regs.get_flag(CpuFlags::Z) { // zero flag set
                 s.extra_cycle = true;
             }
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next opcode
         }
         2 => { // can only be reached if branch condition met
-            // branch ops need one extra cycle if the branch jumps across a page boundary
+            // branch ops need one extra cycle if the branch jumps across a page boundary,
+            // reading from the target before the carry into the high byte is fixed
+            let uncarried = (s.regs.pc & 0xFF00) | (address & 0xFF);
             if s.regs.pc >> 8 != address >> 8 {
                 s.extra_cycle = true;
             }
 
             s.regs.pc = address;
-            
-            Nop
+
+            Read{addr: uncarried}
         }
         _ => Nop,
     }
@@ -775,6 +898,8 @@ pub fn lsr_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
     match cycle {
         1 => Read{addr: address}, // fetch value
         2 => {
+            let unmodified = s.data;
+
             // carry flag contains old LSB
             s.regs.set_flag(CpuFlags::C, s.data & 1 == 1); // work with fetched value
 
@@ -784,10 +909,11 @@ pub fn lsr_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             s.regs.set_flag(CpuFlags::Z, s.data == 0);
         
             // negative flag
-            s.regs.set_flag(CpuFlags::Z, s.data & 0x80 == 0x80);
+            s.regs.set_flag(CpuFlags::N, s.data & 0x80 == 0x80);
         
-            Write{addr: address, data: s.data} // write back changed value
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
         }
+        3 => Write{addr: address, data: s.data}, // write back changed value
         _ => Nop
     }
 }
@@ -810,6 +936,9 @@ pub fn adc_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             // see http://www.righto.com/2012/12/the-6502-overflow-flag-explained.html
             s.regs.set_flag(CpuFlags::V, (s.regs.a as usize ^ result) & (s.data as usize ^ result) & 0x80 > 1);
         
+            // negative flag
+            s.regs.set_flag(CpuFlags::N, result & 0x80 == 0x80);
+
             // load result into accumultoar
             s.regs.a = result as u8;
         
@@ -825,17 +954,19 @@ pub fn bcs_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             if s.regs.get_flag(CpuFlags::C) { // carry flag set
                 s.extra_cycle = true;
             }
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next opcode
         }
         2 => { // can only be reached if branch condition met
-            // branch ops need one extra cycle if the branch jumps across a page boundary
+            // branch ops need one extra cycle if the branch jumps across a page boundary,
+            // reading from the target before the carry into the high byte is fixed
+            let uncarried = (s.regs.pc & 0xFF00) | (address & 0xFF);
             if s.regs.pc >> 8 != address >> 8 {
                 s.extra_cycle = true;
             }
 
             s.regs.pc = address;
-            
-            Nop
+
+            Read{addr: uncarried}
         }
         _ => Nop,
     }
@@ -859,9 +990,9 @@ pub fn ror_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
             s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
 
             // negative flag
-            s.regs.set_flag(CpuFlags::Z, s.regs.a & 0x80 == 0x80);
+            s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
 
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next byte
         }
         _ => Nop
     }
@@ -898,7 +1029,7 @@ pub fn and_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
         
             // negative flag
-            s.regs.set_flag(CpuFlags::Z, s.regs.a & 0x80 == 0x80);
+            s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
         
             Nop
         }
@@ -913,19 +1044,23 @@ pub fn eor_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage
     s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
 
     // negative flag
-    s.regs.set_flag(CpuFlags::Z, s.regs.a & 0x80 == 0x80);
+    s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
 
     Nop
 }
 
 pub fn pla_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
     match cycle {
-        1 => {
+        1 => Read{addr: s.regs.pc}, // dummy read of the next byte
+        2 => Read{addr: 0x100 | s.regs.sp as u16}, // dummy read while the stack pointer is incremented
+        3 => {
             s.regs.sp = s.regs.sp.wrapping_add(1); // increment stack pointer
             Read{addr: 0x100 | s.regs.sp as u16}
         }
-        2 => {
+        4 => {
             s.regs.a = s.data;
+            s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
+            s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
             Nop
         }
         _ => Nop
@@ -939,7 +1074,7 @@ pub fn ora_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage
     s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
 
     // negative flag
-    s.regs.set_flag(CpuFlags::Z, s.regs.a & 0x80 == 0x80);
+    s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
 
     Nop
 }
@@ -965,8 +1100,13 @@ pub fn cmp_immediate(s: &mut CpuState, immediate: u8, cycle: u8) -> BusMessage {
 }
 
 pub fn clc_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.set_flag(CpuFlags::C, false);
-    Nop
+    match cycle {
+        1 => {
+            s.regs.set_flag(CpuFlags::C, false);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn bpl_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
@@ -975,17 +1115,19 @@ pub fn bpl_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             if !s.regs.get_flag(CpuFlags::N) { // negative flag not set
                 s.extra_cycle = true;
             }
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next opcode
         }
         2 => { // can only be reached if branch condition met
-            // branch ops need one extra cycle if the branch jumps across a page boundary
+            // branch ops need one extra cycle if the branch jumps across a page boundary,
+            // reading from the target before the carry into the high byte is fixed
+            let uncarried = (s.regs.pc & 0xFF00) | (address & 0xFF);
             if s.regs.pc >> 8 != address >> 8 {
                 s.extra_cycle = true;
             }
 
             s.regs.pc = address;
-            
-            Nop
+
+            Read{addr: uncarried}
         }
         _ => Nop,
     }
@@ -1001,7 +1143,7 @@ pub fn bit_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             s.regs.set_flag(CpuFlags::Z, result == 0);
 
             // overflow flag <- M6
-            s.regs.set_flag(CpuFlags::V, s.data & 0x70 == 0x70);
+            s.regs.set_flag(CpuFlags::V, s.data & 0x40 == 0x40);
 
             // negative flag <- M7
             s.regs.set_flag(CpuFlags::N, s.data & 0x80 == 0x80);
@@ -1013,8 +1155,15 @@ pub fn bit_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
 }
 
 pub fn txa_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
-    s.regs.a = s.regs.x;
-    Nop
+    match cycle {
+        1 => {
+            s.regs.a = s.regs.x;
+            s.regs.set_flag(CpuFlags::Z, s.regs.a == 0);
+            s.regs.set_flag(CpuFlags::N, s.regs.a & 0x80 == 0x80);
+            Read{addr: s.regs.pc} // dummy read of the next byte
+        }
+        _ => Nop
+    }
 }
 
 pub fn bvc_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
@@ -1023,17 +1172,19 @@ pub fn bvc_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             if !s.regs.get_flag(CpuFlags::V) { // overflow flag not set
                 s.extra_cycle = true;
             }
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next opcode
         }
         2 => { // can only be reached if branch condition met
-            // branch ops need one extra cycle if the branch jumps across a page boundary
+            // branch ops need one extra cycle if the branch jumps across a page boundary,
+            // reading from the target before the carry into the high byte is fixed
+            let uncarried = (s.regs.pc & 0xFF00) | (address & 0xFF);
             if s.regs.pc >> 8 != address >> 8 {
                 s.extra_cycle = true;
             }
 
             s.regs.pc = address;
-            
-            Nop
+
+            Read{addr: uncarried}
         }
         _ => Nop,
     }
@@ -1045,17 +1196,19 @@ pub fn bcc_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
             if !s.regs.get_flag(CpuFlags::C) { // carry flag not set
                 s.extra_cycle = true;
             }
-            Nop
+            Read{addr: s.regs.pc} // dummy read of the next opcode
         }
         2 => { // can only be reached if branch condition met
-            // branch ops need one extra cycle if the branch jumps across a page boundary
+            // branch ops need one extra cycle if the branch jumps across a page boundary,
+            // reading from the target before the carry into the high byte is fixed
+            let uncarried = (s.regs.pc & 0xFF00) | (address & 0xFF);
             if s.regs.pc >> 8 != address >> 8 {
                 s.extra_cycle = true;
             }
 
             s.regs.pc = address;
-            
-            Nop
+
+            Read{addr: uncarried}
         }
         _ => Nop,
     }
@@ -1069,7 +1222,7 @@ pub fn reset(s: &mut CpuState, reset_vector: u16, cycle: u8) -> BusMessage {
             Read{addr: reset_vector}
         },
         7 => {
-            s.regs.pc |= s.data as u16; // set low byte of new PC address
+            s.regs.pc = s.data as u16; // set low byte of new PC address
             Read{addr: reset_vector+1}
         },
         8 => {
@@ -1089,35 +1242,316 @@ pub fn reset(s: &mut CpuState, reset_vector: u16, cycle: u8) -> BusMessage {
             
             Read{addr: s.regs.pc}
         },
-        _ => panic!("Impossible cycle count in match, reset takes 8 cycles"),
+        _ => Nop,
     }
 }
 
 pub fn interrupt(s: &mut CpuState, interrupt_vector: u16, cycle: u8) -> BusMessage {
     match cycle {
-        x if x < 3 => Nop,
-        3 => Write{addr: 0x100 | s.regs.sp as u16, data: (s.regs.pc >> 8) as u8}, // push PC high byte to stack
-        4 => {
+        1 => Read{addr: s.regs.pc}, // dummy read of the next byte
+        2 => Write{addr: 0x100 | s.regs.sp as u16, data: (s.regs.pc >> 8) as u8}, // push PC high byte to stack
+        3 => {
             s.regs.sp = s.regs.sp.wrapping_sub(1);
             Write{addr: 0x100 | s.regs.sp as u16, data: s.regs.pc as u8} // push PC low byte to stack
         }
-        5 => {
+        4 => {
             s.regs.sp = s.regs.sp.wrapping_sub(1);
-            Write{addr: 0x100 | s.regs.sp as u16, data: s.regs.status & !0x10} // push status register with B forced to 0 to stack
+            Write{addr: 0x100 | s.regs.sp as u16, data: (s.regs.status & !(CpuFlags::B as u8)) | 0x20} // push status register with B forced to 0 to stack
         }
-        6 => {
+        5 => {
             s.regs.sp = s.regs.sp.wrapping_sub(1);
             Read{addr: interrupt_vector} // fetch PC low byte from vector
         }
-        7 => {
+        6 => {
             s.regs.pc = s.data as u16; // set PC low byte from stack
             s.regs.set_flag(CpuFlags::I, true); // set interrupt disable flag
             Read{addr: interrupt_vector+1} // fetch PC high byte from vector
         }
-        8 => {
+        7 => {
             s.regs.pc |= (s.data as u16) << 8; // set PC high byte from stack
-            Read{addr: s.regs.pc} // issue fetch for next opcode
+            Nop
+        }
+        _ => Nop
+    }
+}
+
+// unofficial opcodes, see https://www.nesdev.org/wiki/Programming_with_unofficial_opcodes
+
+pub fn kil_implied(s: &mut CpuState, cycle: u8) -> BusMessage {
+    // jams the CPU until the next reset
+    s.halt = true;
+    Nop
+}
+
+pub fn nop_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage {
+    Nop
+}
+
+pub fn nop_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => Read{addr: address}, // the operand is read but ignored
+        _ => Nop,
+    }
+}
+
+pub fn lax_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => Read{addr: address},
+        2 => {
+            s.regs.a = s.data;
+            s.regs.x = s.data;
+            set_nz(s, s.data);
+            Nop
+        }
+        _ => Nop
+    }
+}
+
+pub fn lax_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage {
+    // unstable, the accumulator is ORed with a chip dependent magic constant first
+    s.regs.a = (s.regs.a | 0xEE) & immediate;
+    s.regs.x = s.regs.a;
+    set_nz(s, s.regs.a);
+    Nop
+}
+
+pub fn sax_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => Write{addr: address, data: s.regs.a & s.regs.x},
+        _ => Nop,
+    }
+}
+
+pub fn slo_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => Read{addr: address}, // fetch value
+        2 => {
+            let unmodified = s.data;
+
+            // ASL value, then ORA value
+            s.regs.set_flag(CpuFlags::C, s.data & 0x80 == 0x80);
+            s.data <<= 1;
+            s.regs.a |= s.data;
+            set_nz(s, s.regs.a);
+
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
+        }
+        3 => Write{addr: address, data: s.data}, // write back changed value
+        _ => Nop
+    }
+}
+
+pub fn rla_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => Read{addr: address}, // fetch value
+        2 => {
+            let unmodified = s.data;
+
+            // ROL value, then AND value
+            let carry = s.regs.get_flag(CpuFlags::C) as u8;
+            s.regs.set_flag(CpuFlags::C, s.data & 0x80 == 0x80);
+            s.data = s.data << 1 | carry;
+            s.regs.a &= s.data;
+            set_nz(s, s.regs.a);
+
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
         }
-        _ => panic!("Impossible cycle count in match, interrupt handling takes 8 cycles")
+        3 => Write{addr: address, data: s.data}, // write back changed value
+        _ => Nop
+    }
+}
+
+pub fn sre_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => Read{addr: address}, // fetch value
+        2 => {
+            let unmodified = s.data;
+
+            // LSR value, then EOR value
+            s.regs.set_flag(CpuFlags::C, s.data & 1 == 1);
+            s.data >>= 1;
+            s.regs.a ^= s.data;
+            set_nz(s, s.regs.a);
+
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
+        }
+        3 => Write{addr: address, data: s.data}, // write back changed value
+        _ => Nop
+    }
+}
+
+pub fn rra_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => Read{addr: address}, // fetch value
+        2 => {
+            let unmodified = s.data;
+
+            // ROR value, then ADC value
+            let carry = s.regs.get_flag(CpuFlags::C) as u8;
+            s.regs.set_flag(CpuFlags::C, s.data & 1 == 1);
+            s.data = s.data >> 1 | carry << 7;
+            add_with_carry(s, s.data);
+
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
+        }
+        3 => Write{addr: address, data: s.data}, // write back changed value
+        _ => Nop
+    }
+}
+
+pub fn dcp_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => Read{addr: address}, // fetch value
+        2 => {
+            let unmodified = s.data;
+
+            // DEC value, then CMP value
+            s.data = s.data.wrapping_sub(1);
+            compare(s, s.regs.a, s.data);
+
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
+        }
+        3 => Write{addr: address, data: s.data}, // write back changed value
+        _ => Nop
+    }
+}
+
+pub fn isc_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => Read{addr: address}, // fetch value
+        2 => {
+            let unmodified = s.data;
+
+            // INC value, then SBC value
+            s.data = s.data.wrapping_add(1);
+            add_with_carry(s, s.data ^ 0xFF);
+
+            Write{addr: address, data: unmodified} // dummy write while the ALU works
+        }
+        3 => Write{addr: address, data: s.data}, // write back changed value
+        _ => Nop
+    }
+}
+
+pub fn anc_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage {
+    // AND immediate, then copy N to C
+    s.regs.a &= immediate;
+    set_nz(s, s.regs.a);
+    s.regs.set_flag(CpuFlags::C, s.regs.a & 0x80 == 0x80);
+    Nop
+}
+
+pub fn alr_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage {
+    // AND immediate, then LSR A
+    s.regs.a &= immediate;
+    s.regs.set_flag(CpuFlags::C, s.regs.a & 1 == 1);
+    s.regs.a >>= 1;
+    set_nz(s, s.regs.a);
+    Nop
+}
+
+pub fn arr_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage {
+    // AND immediate, then ROR A with C and V set from bits 6 and 5 of the result
+    let carry = s.regs.get_flag(CpuFlags::C) as u8;
+    s.regs.a = (s.regs.a & immediate) >> 1 | carry << 7;
+    set_nz(s, s.regs.a);
+    s.regs.set_flag(CpuFlags::C, s.regs.a & 0x40 == 0x40);
+    s.regs.set_flag(CpuFlags::V, (s.regs.a >> 6 ^ s.regs.a >> 5) & 1 == 1);
+    Nop
+}
+
+pub fn axs_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage {
+    // X = (A & X) - immediate, without borrow and setting flags like CMP
+    let value = s.regs.a & s.regs.x;
+    compare(s, value, immediate);
+    s.regs.x = value.wrapping_sub(immediate);
+    Nop
+}
+
+pub fn xaa_immediate(s: &mut CpuState, immediate: u8, _cycle: u8) -> BusMessage {
+    // unstable, the accumulator is ORed with a chip dependent magic constant first
+    s.regs.a = (s.regs.a | 0xEE) & s.regs.x & immediate;
+    set_nz(s, s.regs.a);
+    Nop
+}
+
+pub fn las_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => Read{addr: address},
+        2 => {
+            let value = s.data & s.regs.sp;
+            s.regs.a = value;
+            s.regs.x = value;
+            s.regs.sp = value;
+            set_nz(s, value);
+            Nop
+        }
+        _ => Nop
+    }
+}
+
+pub fn sha_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => unstable_store(address, s.regs.y, s.regs.a & s.regs.x),
+        _ => Nop,
+    }
+}
+
+pub fn shx_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => unstable_store(address, s.regs.y, s.regs.x),
+        _ => Nop,
     }
 }
+
+pub fn shy_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => unstable_store(address, s.regs.x, s.regs.y),
+        _ => Nop,
+    }
+}
+
+pub fn tas_address(s: &mut CpuState, address: u16, cycle: u8) -> BusMessage {
+    match cycle {
+        1 => {
+            s.regs.sp = s.regs.a & s.regs.x;
+            unstable_store(address, s.regs.y, s.regs.sp)
+        }
+        _ => Nop,
+    }
+}
+
+// SHA, SHX, SHY and TAS store the value ANDed with the high byte of the base address plus one,
+// on a page crossing that value also replaces the high byte of the target address
+fn unstable_store(address: u16, index: u8, value: u8) -> BusMessage {
+    let base = address.wrapping_sub(index as u16);
+    let data = value & ((base >> 8) as u8).wrapping_add(1);
+
+    if base >> 8 != address >> 8 {
+        Write{addr: (data as u16) << 8 | (address & 0xFF), data}
+    } else {
+        Write{addr: address, data}
+    }
+}
+
+fn set_nz(s: &mut CpuState, value: u8) {
+    s.regs.set_flag(CpuFlags::Z, value == 0);
+    s.regs.set_flag(CpuFlags::N, value & 0x80 == 0x80);
+}
+
+fn compare(s: &mut CpuState, register: u8, value: u8) {
+    s.regs.set_flag(CpuFlags::C, register >= value);
+    set_nz(s, register.wrapping_sub(value));
+}
+
+// SBC is ADC with the inverted operand, the 2A03 has no decimal mode
+fn add_with_carry(s: &mut CpuState, value: u8) {
+    let result = s.regs.a as u16 + value as u16 + s.regs.get_flag(CpuFlags::C) as u16;
+
+    // signed overflow flag, V = (A^result) & (M^result) & 0x80
+    s.regs.set_flag(CpuFlags::V, (s.regs.a as u16 ^ result) & (value as u16 ^ result) & 0x80 != 0);
+    s.regs.set_flag(CpuFlags::C, result > 0xFF);
+
+    s.regs.a = result as u8;
+    set_nz(s, s.regs.a);
+}
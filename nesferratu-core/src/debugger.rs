@@ -7,9 +7,14 @@ use std::{collections::VecDeque, fmt::Display};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
-use crate::{Emulator, cpu::{CpuRegisters, EmulationState}};
+use crate::{BusAccess, BusAccessKind, Emulator, cpu::{CpuError, CpuRegisters, EmulationState}};
 use crate::cpu::instructions::{Instruction, Operand};
 
+pub mod trace;
+pub mod expr;
+
+use expr::{Expr, ExprError};
+
 pub trait CpuDebugger {
     fn get_cpu_regs(&self) -> &CpuRegisters;
     fn get_cup_regs_mut(&mut self) -> Option<&mut CpuRegisters>;
@@ -24,62 +29,215 @@ pub trait MemDebugger {
 }
 
 lazy_static! {
-    static ref CMD_REGEXES: Vec<(CommandDelegate, Regex, usize)> = vec![
-        (commands::cycle, Regex::new(r"c(?:ycle)?").unwrap(), 1),
-        (commands::step, Regex::new(r"s(?:tep)?").unwrap(), 1),
-        (commands::run, Regex::new(r"r(?:un)?").unwrap(), 0),
+    static ref CMD_REGEXES: Vec<(CommandDelegate, Regex, usize, ExprArg)> = vec![
+        (commands::cycle, Regex::new(r"^c(?:ycle)?\b").unwrap(), 1, ExprArg::None),
+        (commands::step, Regex::new(r"^s(?:tep)?\b").unwrap(), 1, ExprArg::None),
+        (commands::run, Regex::new(r"^r(?:un)?\b").unwrap(), 0, ExprArg::None),
+        (commands::add_breakpoint, Regex::new(r"^b(?:reak)?\b").unwrap(), 1, ExprArg::Condition),
+        (commands::delete_breakpoint, Regex::new(r"^d(?:elete)?\b").unwrap(), 1, ExprArg::None),
+        (commands::enable_breakpoint, Regex::new(r"^en(?:able)?\b").unwrap(), 1, ExprArg::None),
+        (commands::disable_breakpoint, Regex::new(r"^dis(?:able)?\b").unwrap(), 1, ExprArg::None),
+        (commands::add_write_watchpoint, Regex::new(r"^w(?:atch)?\b").unwrap(), 2, ExprArg::Condition),
+        (commands::add_read_watchpoint, Regex::new(r"^rw(?:atch)?\b").unwrap(), 2, ExprArg::Condition),
+        (commands::add_access_watchpoint, Regex::new(r"^aw(?:atch)?\b").unwrap(), 2, ExprArg::Condition),
+        (commands::info_breakpoints, Regex::new(r"^i(?:nfo)?\s+b(?:reak(?:points)?)?\b").unwrap(), 0, ExprArg::None),
+        (commands::print, Regex::new(r"^p(?:rint)?\b").unwrap(), 0, ExprArg::Rest),
+        (commands::examine, Regex::new(r"^x\b").unwrap(), 3, ExprArg::None),
+        (commands::poke, Regex::new(r"^poke\b").unwrap(), 258, ExprArg::None),
+        (commands::fill, Regex::new(r"^f(?:ill)?\b").unwrap(), 4, ExprArg::None),
+        (commands::set, Regex::new(r"^set\b").unwrap(), 0, ExprArg::Assignment),
     ];
 
-    static ref ARG_UINT: Regex = Regex::new(r"\d+").unwrap();
+    static ref CONDITION: Regex = Regex::new(r"(?:^|\s)if\s").unwrap();
+
+    static ref ARG_UINT: Regex = Regex::new(r"^\d+$").unwrap();
+    static ref ARG_HEX: Regex = Regex::new(r"^(?:\$|0x)([0-9a-fA-F]+)$").unwrap();
 }
 
 #[derive(Debug, Clone)]
 pub enum Arg {
     UInt(u32),
     String(String),
+    Expr(Expr),
+    Condition(Expr),    // trailing "if <expr>"
+}
+
+// how a command takes an expression besides its normal arguments
+enum ExprArg {
+    None,
+    Condition,  // optional "if <expr>" after the arguments
+    Rest,       // the whole rest of the command is one expression
+    Assignment, // "<target> = <expr>", the target is passed as a string
 }
 
 impl Arg {
     fn parse(s: &str) -> Option<Self> {
         match s {
-            s if ARG_UINT.is_match(s) => {
-                match s.parse() {
-                    Ok(i) => Some(Self::UInt(i)),
-                    Err(_) => None,
-                }
-            },
+            s if ARG_UINT.is_match(s) || ARG_HEX.is_match(s) => parse_uint(s).map(Self::UInt),
 
             _ => Some(Self::String(s.to_owned())),
         }
     }
 }
 
+// decimal or hex numbers like $C000 or 0xC000
+fn parse_uint(s: &str) -> Option<u32> {
+    if let Some(caps) = ARG_HEX.captures(s) {
+        u32::from_str_radix(&caps[1], 16).ok()
+    } else if ARG_UINT.is_match(s) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
 impl Display for Arg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Arg::UInt(i) => write!(f, "{}", i),
-            Arg::String(s) => write!(f, "{}", s)
+            Arg::String(s) => write!(f, "{}", s),
+            Arg::Expr(e) => write!(f, "{}", e),
+            Arg::Condition(e) => write!(f, "if {}", e),
         }
     }
 }
 
-type CommandDelegate = fn(d: &mut Debugger, args: &Vec<Arg>) -> Result<(), CommandRunError>;
+type CommandDelegate = fn(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError>;
 
 pub enum CommandParseError {
     EmptyInput,
     UnknownCommand,
     InvalidArgument{index: usize}, // index of invalid argument
     InvalidArgumentNum{expected: usize, got: usize}, // expected number, actual number
+    InvalidExpression(ExprError),
 }
 
 pub enum CommandRunError {
     InvalidArgumentType(usize, Arg, Arg), // index of invalid argument, expected type, actual type
+    MissingArgument(usize), // index of missing argument
+    AddressOutOfRange(usize, u32), // index of invalid argument, value
+    InvalidRange(usize), // index of invalid argument
+    ValueOutOfRange(usize, u32), // index of invalid argument, value
+    NotWritable(u16), // address
+    InvalidTarget(String),
+    InvalidAssignment{target: String, value: i64},
+    MidInstruction,
+    NoSuchBreakpoint(u32),
+    MisplacedSpace(usize), // index of a memory space name that isn't the first argument
+}
+
+// address spaces for the memory commands
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MemSpace {
+    Cpu,
+    Ppu,
+    Oam,
+}
+
+impl MemSpace {
+    fn from_name(name: &str) -> Option<MemSpace> {
+        match name.to_ascii_lowercase().as_str() {
+            "cpu" => Some(MemSpace::Cpu),
+            "ppu" => Some(MemSpace::Ppu),
+            "oam" => Some(MemSpace::Oam),
+            _ => None,
+        }
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            MemSpace::Cpu => 0x10000,
+            MemSpace::Ppu => 0x4000,
+            MemSpace::Oam => 0x100,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            MemSpace::Cpu => "CPU",
+            MemSpace::Ppu => "PPU",
+            MemSpace::Oam => "OAM",
+        }
+    }
+}
+
+pub struct Breakpoint {
+    pub id: u32,
+    pub addr: u16,
+    pub condition: Option<Expr>,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WatchKind {
+    Read,
+    Write,
+    Access, // read or write
+}
+
+// watchpoints share their numbers with breakpoints
+pub struct Watchpoint {
+    pub id: u32,
+    pub kind: WatchKind,
+    pub start: u16,
+    pub end: u16,           // inclusive
+    pub value: Option<u8>,  // only trips when this value is read or written
+    pub condition: Option<Expr>,
+    pub enabled: bool,
+}
+
+impl Watchpoint {
+    fn matches(&self, access: &BusAccess) -> bool {
+        let kind = matches!(
+            (self.kind, access.kind),
+            (WatchKind::Access, _) | (WatchKind::Read, BusAccessKind::Read) | (WatchKind::Write, BusAccessKind::Write)
+        );
+
+        self.enabled
+            && kind
+            && (self.start..=self.end).contains(&access.addr)
+            && self.value.is_none_or(|v| v == access.data)
+    }
+}
+
+// breakpoints and watchpoints without a condition always stop, as do conditions that fail to evaluate
+fn condition_met(condition: &Option<Expr>, emu: &Emulator) -> bool {
+    match condition {
+        Some(expr) => match expr.is_true(emu) {
+            Ok(met) => met,
+            Err(e) => {
+                eprintln!("Could not evaluate condition \"{}\": {}", expr, e);
+                true
+            }
+        },
+        None => true,
+    }
+}
+
+pub struct WatchHit {
+    pub id: u32,
+    pub pc: u16,    // start of the instruction that made the access
+    pub access: BusAccess,
+}
+
+impl Display for WatchHit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let a = &self.access;
+        match a.kind {
+            BusAccessKind::Read => write!(f, "Watchpoint {}: read ${:04X} = ${:02X} at PC ${:04X}", self.id, a.addr, a.data, self.pc),
+            BusAccessKind::Write => match a.old {
+                Some(old) => write!(f, "Watchpoint {}: write ${:04X}: ${:02X} -> ${:02X} at PC ${:04X}", self.id, a.addr, old, a.data, self.pc),
+                None => write!(f, "Watchpoint {}: write ${:04X} = ${:02X} at PC ${:04X}", self.id, a.addr, a.data, self.pc),
+            },
+        }
+    }
 }
 
 pub struct Command {
     cmd: String,
     delegate: CommandDelegate,
     args: Vec<Arg>,
+    source: String, // the command as it was typed, for display
 }
 
 impl std::fmt::Debug for Command {
@@ -93,7 +251,7 @@ impl std::fmt::Debug for Command {
 
 impl Command {
     pub fn parse(input: &str) -> Result<Vec<Self>, CommandParseError> {
-        if input.len() == 0 {
+        if input.is_empty() {
             return Err(CommandParseError::EmptyInput);
         }
 
@@ -101,20 +259,24 @@ impl Command {
 
         for mut substr in input.split(';') {
             substr = substr.trim();
+            let source = substr;
 
             let mut cmd: Option<Command> = None;
             let mut argnum = 0;
+            let mut expr_arg = &ExprArg::None;
             
-            for (delegate, regex, num) in CMD_REGEXES.iter() {
+            for (delegate, regex, num, expr) in CMD_REGEXES.iter() {
 
                 if let Some(m) = regex.find(substr) {
                     substr = &substr[m.end()..];
                     
                     argnum = *num;
+                    expr_arg = expr;
                     cmd = Some(Command {
                         cmd: m.as_str().to_owned(),
                         delegate: *delegate,
                         args: Vec::new(),
+                        source: source.to_owned(),
                     });
                     break;
                 }
@@ -124,6 +286,31 @@ impl Command {
                 None => return Err(CommandParseError::UnknownCommand),
                 Some(mut cmd) => {
 
+                    let mut condition = None;
+
+                    match expr_arg {
+                        ExprArg::None => {}
+                        ExprArg::Condition => {
+                            if let Some(m) = CONDITION.find(substr) {
+                                let expr = Expr::parse(&substr[m.end()..]).map_err(CommandParseError::InvalidExpression)?;
+                                condition = Some(Arg::Condition(expr));
+                                substr = &substr[..m.start()];
+                            }
+                        }
+                        ExprArg::Rest => {
+                            let expr = Expr::parse(substr).map_err(CommandParseError::InvalidExpression)?;
+                            cmd.args.push(Arg::Expr(expr));
+                            substr = "";
+                        }
+                        ExprArg::Assignment => {
+                            let (target, value) = substr.split_once('=').ok_or(CommandParseError::InvalidArgument{index: 0})?;
+                            let expr = Expr::parse(value).map_err(CommandParseError::InvalidExpression)?;
+                            cmd.args.push(Arg::String(target.trim().to_owned()));
+                            cmd.args.push(Arg::Expr(expr));
+                            substr = "";
+                        }
+                    }
+
                     for (i, token) in substr.split_ascii_whitespace().enumerate() {
 
                         if i < argnum {
@@ -137,6 +324,7 @@ impl Command {
                         }
                     }
 
+                    cmd.args.extend(condition);
                     output.push(cmd);
                 }
             }
@@ -148,11 +336,7 @@ impl Command {
 
 impl Display for Command {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.cmd)?;
-        for arg in &self.args {
-            write!(f, " {}", arg)?;
-        }
-        Ok(())
+        write!(f, "{}", self.source)
     }
 }
 
@@ -162,24 +346,31 @@ pub struct Debugger {
     last_command: Option<Command>,
     interrupted: Arc<AtomicBool>,
     disasm_history: VecDeque<String>,
+    breakpoints: Vec<Breakpoint>,
+    watchpoints: Vec<Watchpoint>,
+    next_breakpoint_id: u32,
+    watch_hit: Option<WatchHit>,
+    instruction_pc: u16,
 }
 
 impl Debugger {
 
-    pub fn new(emu: Emulator) -> Debugger {
-        let interrupted = Arc::new(AtomicBool::new(false));
+    pub fn new(mut emu: Emulator) -> Debugger {
+        // watchpoints need to see every bus access
+        emu.set_bus_watch(true);
+        let instruction_pc = emu.cpu.get_cpu_regs().pc;
 
-        let i = interrupted.clone();
-        ctrlc::set_handler(move || {
-            i.store(true, Ordering::SeqCst);
-        }).expect("Error setting Ctrl-C handler");
-        
         Debugger {
             emu,
             commands: VecDeque::new(),
             last_command: None,
-            interrupted,
+            interrupted: Arc::new(AtomicBool::new(false)),
             disasm_history: VecDeque::new(),
+            breakpoints: Vec::new(),
+            watchpoints: Vec::new(),
+            next_breakpoint_id: 1,
+            watch_hit: None,
+            instruction_pc,
         }
     }
 
@@ -187,8 +378,26 @@ impl Debugger {
         self.commands.extend(cmds);
     }
 
-    pub fn cycle(&mut self) {
-        self.emu.clock();
+    // doesn't clock the emulator any further once the CPU is jammed
+    pub fn cycle(&mut self) -> Result<(), CpuError> {
+        if let Some(e) = self.emu.cpu_jam() {
+            return Err(e);
+        }
+
+        if self.emu.cpu.get_emulation_state().instruction_done {
+            self.instruction_pc = self.emu.cpu.get_cpu_regs().pc;
+        }
+
+        let result = self.emu.clock();
+
+        for access in self.emu.bus_accesses() {
+            if let Some(w) = self.watchpoints.iter().find(|w| w.matches(access) && condition_met(&w.condition, &self.emu)) {
+                self.watch_hit = Some(WatchHit { id: w.id, pc: self.instruction_pc, access: *access });
+                break;
+            }
+        }
+
+        result?;
 
         if self.emu.cpu.get_emulation_state().instruction_done {
             if self.disasm_history.len() >= 10 {
@@ -200,17 +409,67 @@ impl Debugger {
                 self.emu.cpu.get_raw_instruction().unwrap())
                 );
         }
+
+        Ok(())
+    }
+
+    // enabled breakpoint at the PC, only checked between two instructions
+    pub fn breakpoint_hit(&self) -> Option<&Breakpoint> {
+        if !self.emu.cpu.get_emulation_state().instruction_done {
+            return None;
+        }
+
+        let pc = self.emu.cpu.get_cpu_regs().pc;
+        self.breakpoints.iter().find(|b| b.enabled && b.addr == pc && condition_met(&b.condition, &self.emu))
+    }
+
+    // contents of a whole address space as seen by the debugger, reading has no side effects
+    pub fn read_mem(&self, space: MemSpace) -> Vec<u8> {
+        match space {
+            MemSpace::Cpu => (0..=0xFFFF).map(|addr| self.emu.peek_cpu(addr)).collect(),
+            MemSpace::Ppu => (0..0x4000).map(|addr| self.emu.peek_ppu(addr)).collect(),
+            MemSpace::Oam => self.emu.ppu.oam().to_vec(),
+        }
+    }
+
+    // false if the address can't be written, e.g. ROM or I/O registers
+    pub fn write_mem(&mut self, space: MemSpace, addr: u16, data: u8) -> bool {
+        match space {
+            MemSpace::Cpu => self.emu.poke_cpu(addr, data),
+            MemSpace::Ppu => {
+                self.emu.poke_ppu(addr, data);
+                true
+            }
+            MemSpace::Oam => {
+                self.emu.ppu.oam_mut()[addr as usize & 0xFF] = data;
+                true
+            }
+        }
     }
 
-    pub fn step(&mut self) {
-        self.cycle();
+    // watchpoint that tripped since the last call
+    pub fn take_watch_hit(&mut self) -> Option<WatchHit> {
+        self.watch_hit.take()
+    }
 
-        while !self.emu.cpu.get_emulation_state().instruction_done {
-            self.cycle();
+    // stops in the middle of the instruction when a watchpoint trips
+    pub fn step(&mut self) -> Result<(), CpuError> {
+        self.cycle()?;
+
+        while !self.emu.cpu.get_emulation_state().instruction_done && self.watch_hit.is_none() {
+            self.cycle()?;
         }
+
+        Ok(())
     }
 
+    // takes over Ctrl-C for the rest of the process to interrupt running commands
     pub fn run(&mut self) {
+        let i = self.interrupted.clone();
+        ctrlc::set_handler(move || {
+            i.store(true, Ordering::SeqCst);
+        }).expect("Error setting Ctrl-C handler");
+
         let mut rl = Editor::<()>::new();
         
         if rl.load_history("debugger_history.txt").is_err() {
@@ -218,7 +477,7 @@ impl Debugger {
         }
         
         loop {
-            if self.commands.len() > 0 {
+            if !self.commands.is_empty() {
                 let cmd = self.commands.pop_front().unwrap();
                 
                 // run actual debugger command
@@ -230,6 +489,16 @@ impl Debugger {
                         eprintln!("Could not run command \"{}\"", cmd);
                         match e {
                             CommandRunError::InvalidArgumentType(i, exp, got) => eprintln!("Invalid argument type at position {}, expected {:?}, got {:?}", i+1, exp, got),
+                            CommandRunError::MissingArgument(i) => eprintln!("Missing argument at position {}", i+1),
+                            CommandRunError::AddressOutOfRange(i, addr) => eprintln!("Address {:#X} at position {} is out of range", addr, i+1),
+                            CommandRunError::InvalidRange(i) => eprintln!("Invalid address range at position {}", i+1),
+                            CommandRunError::ValueOutOfRange(i, v) => eprintln!("Value {:#X} at position {} is out of range", v, i+1),
+                            CommandRunError::NotWritable(addr) => eprintln!("Address ${:04X} is not writable", addr),
+                            CommandRunError::InvalidTarget(target) => eprintln!("\"{}\" is not a register or flag", target),
+                            CommandRunError::InvalidAssignment{ target, value } => eprintln!("Value {} is out of range for {}", value, target),
+                            CommandRunError::MidInstruction => eprintln!("Registers can only be changed between instructions, step to finish the current one"),
+                            CommandRunError::NoSuchBreakpoint(id) => eprintln!("No breakpoint number {}", id),
+                            CommandRunError::MisplacedSpace(i) => eprintln!("The memory space at position {} has to come before the address", i+1),
                         }
                     }
                 }
@@ -255,6 +524,7 @@ impl Debugger {
                                     CommandParseError::UnknownCommand => eprintln!("Unknown command"),
                                     CommandParseError::InvalidArgument{ index } => eprintln!("Invalid argument at position {}", index+1),
                                     CommandParseError::InvalidArgumentNum{ expected, got } => eprintln!("Invalid number of arguments: expected {}, got {}", expected, got),
+                                    CommandParseError::InvalidExpression(e) => eprintln!("Invalid expression: {}", e),
                                 }
                             }
                         }
@@ -286,6 +556,10 @@ impl Debugger {
     fn display(&self) {
         println!("{}", self.emu.cpu.get_cpu_regs());
 
+        if let Some(e) = self.emu.cpu_jam() {
+            println!("{}", e);
+        }
+
         // disassembly
         println!("┌──────────────────────────────────────────────────────────────────┐");
         println!("│ Disassembly                                                      │");
@@ -303,7 +577,7 @@ impl Debugger {
             s.push_str(&format!("{:#04X} │ ", self.emu.cpu.get_cpu_regs().pc - ins.bytes as u16));
             
             let mut bytes = String::new();
-            if raw.len() > 0 {
+            if !raw.is_empty() {
                 for b in raw.iter() {
                     bytes.push_str(&format!("{:02X} ", b));
                 }
@@ -311,7 +585,11 @@ impl Debugger {
             }
 
             s.push_str(&format!("{:<9}│ ", bytes));
-            
+
+            // unofficial opcodes are marked with a star like in nestest logs
+            if ins.unofficial {
+                s.push('*');
+            }
             s.push_str(ins.mnemonic);
         
             if let Some(op) = op {
@@ -352,12 +630,121 @@ impl Debugger {
 mod commands {
 use std::sync::atomic::Ordering;
 
-    use crate::debugger::{Debugger, Arg, CommandRunError};
+    use crate::debugger::{Debugger, Arg, Breakpoint, CommandRunError, MemSpace, Watchpoint, WatchKind, hex_print, parse_uint};
+    use crate::debugger::expr::{Expr, Var};
+    use crate::debugger::CpuDebugger;
+
+    fn uint_arg(args: &[Arg], i: usize) -> Result<u32, CommandRunError> {
+        match args.get(i) {
+            Some(Arg::UInt(n)) => Ok(*n),
+            Some(arg) => Err(CommandRunError::InvalidArgumentType(i, Arg::UInt(0), arg.clone())),
+            None => Err(CommandRunError::MissingArgument(i)),
+        }
+    }
+
+    fn addr_arg(args: &[Arg], i: usize) -> Result<u16, CommandRunError> {
+        let n = uint_arg(args, i)?;
+        if n > 0xFFFF {
+            return Err(CommandRunError::AddressOutOfRange(i, n));
+        }
+        Ok(n as u16)
+    }
+
+    fn byte_arg(args: &[Arg], i: usize) -> Result<u8, CommandRunError> {
+        let n = uint_arg(args, i)?;
+        if n > 0xFF {
+            return Err(CommandRunError::ValueOutOfRange(i, n));
+        }
+        Ok(n as u8)
+    }
 
-    pub fn cycle(d: &mut Debugger, args: &Vec<Arg>) -> Result<(), CommandRunError> {
+    // optional address space in front of the other arguments, returns the index of the next argument
+    fn space_arg(args: &[Arg]) -> Result<(MemSpace, usize), CommandRunError> {
+        let (space, i) = match args.first() {
+            Some(Arg::String(s)) => match MemSpace::from_name(s) {
+                Some(space) => (space, 1),
+                None => (MemSpace::Cpu, 0),
+            },
+            _ => (MemSpace::Cpu, 0),
+        };
+
+        // a space name after the address would otherwise be ignored, like in "x $10 16 oam"
+        let misplaced = args.iter().skip(i).position(|arg| matches!(arg, Arg::String(s) if MemSpace::from_name(s).is_some()));
+        match misplaced {
+            Some(j) => Err(CommandRunError::MisplacedSpace(i + j)),
+            None => Ok((space, i)),
+        }
+    }
+
+    fn space_addr_arg(args: &[Arg], i: usize, space: MemSpace) -> Result<u16, CommandRunError> {
+        let n = uint_arg(args, i)?;
+        if n as usize >= space.size() {
+            return Err(CommandRunError::AddressOutOfRange(i, n));
+        }
+        Ok(n as u16)
+    }
+
+    // the optional "if <expr>" is always the last argument
+    fn condition_arg(args: &[Arg]) -> Option<Expr> {
+        match args.last() {
+            Some(Arg::Condition(expr)) => Some(expr.clone()),
+            _ => None,
+        }
+    }
+
+    // " if <expr>" for listings
+    fn condition_suffix(condition: &Option<Expr>) -> String {
+        match condition {
+            Some(expr) => format!(" if {}", expr),
+            None => String::new(),
+        }
+    }
+
+    // single address or an inclusive range like $0300-$03FF
+    fn range_arg(args: &[Arg], i: usize) -> Result<(u16, u16), CommandRunError> {
+        let (start, end) = match args.get(i) {
+            Some(Arg::String(s)) => {
+                let (start, end) = s.split_once('-').ok_or(CommandRunError::InvalidRange(i))?;
+                let start = parse_uint(start).ok_or(CommandRunError::InvalidRange(i))?;
+                let end = parse_uint(end).ok_or(CommandRunError::InvalidRange(i))?;
+                (start, end)
+            }
+            _ => {
+                let addr = uint_arg(args, i)?;
+                (addr, addr)
+            }
+        };
+
+        if start > end {
+            return Err(CommandRunError::InvalidRange(i));
+        }
+        if end > 0xFFFF {
+            return Err(CommandRunError::AddressOutOfRange(i, end));
+        }
+
+        Ok((start as u16, end as u16))
+    }
+
+    // reports why execution stopped, a tripped watchpoint or a breakpoint at the PC
+    fn report_stop(d: &mut Debugger) -> bool {
+        if let Some(hit) = d.take_watch_hit() {
+            println!("{}", hit);
+            return true;
+        }
+
+        match d.breakpoint_hit() {
+            Some(b) => {
+                println!("Breakpoint {} at ${:04X}", b.id, b.addr);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn cycle(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
         let cycles;
 
-        if args.len() == 0 {
+        if args.is_empty() {
             cycles = 1;
         } else {
             if let Arg::UInt(i) = args[0] {
@@ -370,7 +757,14 @@ use std::sync::atomic::Ordering;
         d.interrupted.store(false, Ordering::SeqCst);
 
         for _ in 0..cycles {
-            d.cycle();
+            if let Err(e) = d.cycle() {
+                eprintln!("{}", e);
+                break;
+            }
+
+            if report_stop(d) {
+                break;
+            }
 
             if d.interrupted.load(Ordering::SeqCst){
                 break;
@@ -380,10 +774,10 @@ use std::sync::atomic::Ordering;
         Ok(())
     }
 
-    pub fn step(d: &mut Debugger, args: &Vec<Arg>) -> Result<(), CommandRunError> {
+    pub fn step(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
         let steps;
 
-        if args.len() == 0 {
+        if args.is_empty() {
             steps = 1;
         } else {
             if let Arg::UInt(i) = args[0] {
@@ -396,7 +790,14 @@ use std::sync::atomic::Ordering;
         d.interrupted.store(false, Ordering::SeqCst);
 
         for _ in 0..steps {
-            d.step();
+            if let Err(e) = d.step() {
+                eprintln!("{}", e);
+                break;
+            }
+
+            if report_stop(d) {
+                break;
+            }
 
             if d.interrupted.load(Ordering::SeqCst){
                 break;
@@ -406,11 +807,288 @@ use std::sync::atomic::Ordering;
         Ok(())
     }
 
-    pub fn run(d: &mut Debugger, _args: &Vec<Arg>) -> Result<(), CommandRunError> {
+    pub fn run(d: &mut Debugger, _args: &[Arg]) -> Result<(), CommandRunError> {
         d.interrupted.store(false, Ordering::SeqCst);
 
         while !d.interrupted.load(Ordering::SeqCst) {
-            d.cycle();
+            if let Err(e) = d.cycle() {
+                eprintln!("{}", e);
+                break;
+            }
+
+            if report_stop(d) {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn add_breakpoint(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        let addr = addr_arg(args, 0)?;
+        let condition = condition_arg(args);
+        let id = d.next_breakpoint_id;
+        d.next_breakpoint_id += 1;
+
+        println!("Breakpoint {} at ${:04X}{}", id, addr, condition_suffix(&condition));
+        d.breakpoints.push(Breakpoint { id, addr, condition, enabled: true });
+
+        Ok(())
+    }
+
+    // usage: watch <addr>[-<end>] [value] [if <expr>]
+    fn add_watchpoint(d: &mut Debugger, args: &[Arg], kind: WatchKind) -> Result<(), CommandRunError> {
+        let (start, end) = range_arg(args, 0)?;
+        let condition = condition_arg(args);
+
+        let value = match args.get(1) {
+            None | Some(Arg::Condition(_)) => None,
+            Some(_) => {
+                let v = uint_arg(args, 1)?;
+                if v > 0xFF {
+                    return Err(CommandRunError::ValueOutOfRange(1, v));
+                }
+                Some(v as u8)
+            }
+        };
+
+        let id = d.next_breakpoint_id;
+        d.next_breakpoint_id += 1;
+
+        println!("Watchpoint {} on ${:04X}-${:04X}{}", id, start, end, condition_suffix(&condition));
+        d.watchpoints.push(Watchpoint { id, kind, start, end, value, condition, enabled: true });
+
+        Ok(())
+    }
+
+    pub fn add_write_watchpoint(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        add_watchpoint(d, args, WatchKind::Write)
+    }
+
+    pub fn add_read_watchpoint(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        add_watchpoint(d, args, WatchKind::Read)
+    }
+
+    pub fn add_access_watchpoint(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        add_watchpoint(d, args, WatchKind::Access)
+    }
+
+    // deletes all breakpoints and watchpoints without an argument
+    pub fn delete_breakpoint(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        if args.is_empty() {
+            d.breakpoints.clear();
+            d.watchpoints.clear();
+            return Ok(());
+        }
+
+        let id = uint_arg(args, 0)?;
+        if let Some(i) = d.breakpoints.iter().position(|b| b.id == id) {
+            d.breakpoints.remove(i);
+            Ok(())
+        } else if let Some(i) = d.watchpoints.iter().position(|w| w.id == id) {
+            d.watchpoints.remove(i);
+            Ok(())
+        } else {
+            Err(CommandRunError::NoSuchBreakpoint(id))
+        }
+    }
+
+    fn set_breakpoint_enabled(d: &mut Debugger, args: &[Arg], enabled: bool) -> Result<(), CommandRunError> {
+        if args.is_empty() {
+            for b in d.breakpoints.iter_mut() {
+                b.enabled = enabled;
+            }
+            for w in d.watchpoints.iter_mut() {
+                w.enabled = enabled;
+            }
+            return Ok(());
+        }
+
+        let id = uint_arg(args, 0)?;
+        if let Some(b) = d.breakpoints.iter_mut().find(|b| b.id == id) {
+            b.enabled = enabled;
+            Ok(())
+        } else if let Some(w) = d.watchpoints.iter_mut().find(|w| w.id == id) {
+            w.enabled = enabled;
+            Ok(())
+        } else {
+            Err(CommandRunError::NoSuchBreakpoint(id))
+        }
+    }
+
+    pub fn enable_breakpoint(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        set_breakpoint_enabled(d, args, true)
+    }
+
+    pub fn disable_breakpoint(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        set_breakpoint_enabled(d, args, false)
+    }
+
+    pub fn info_breakpoints(d: &mut Debugger, _args: &[Arg]) -> Result<(), CommandRunError> {
+        if d.breakpoints.is_empty() && d.watchpoints.is_empty() {
+            println!("No breakpoints or watchpoints");
+            return Ok(());
+        }
+
+        let enabled = |e: bool| if e { "yes" } else { "no" };
+
+        let mut lines = Vec::new();
+        for b in d.breakpoints.iter() {
+            lines.push((b.id, format!("{:<4} {:<11} {:<8} ${:04X}{}", b.id, "breakpoint", enabled(b.enabled), b.addr, condition_suffix(&b.condition))));
+        }
+        for w in d.watchpoints.iter() {
+            let kind = match w.kind {
+                WatchKind::Read => "rwatch",
+                WatchKind::Write => "watch",
+                WatchKind::Access => "awatch",
+            };
+            let value = match w.value {
+                Some(v) => format!(" if value is ${:02X}", v),
+                None => String::new(),
+            };
+            lines.push((w.id, format!("{:<4} {:<11} {:<8} ${:04X}-${:04X}{}{}", w.id, kind, enabled(w.enabled), w.start, w.end, value, condition_suffix(&w.condition))));
+        }
+        lines.sort_by_key(|(id, _)| *id);
+
+        println!("{:<4} {:<11} {:<8} Address", "Num", "Type", "Enabled");
+        for (_, line) in lines.iter() {
+            println!("{}", line);
+        }
+
+        Ok(())
+    }
+
+    pub fn print(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        let expr = match args.first() {
+            Some(Arg::Expr(expr)) => expr,
+            Some(arg) => return Err(CommandRunError::InvalidArgumentType(0, Arg::String(String::new()), arg.clone())),
+            None => return Err(CommandRunError::MissingArgument(0)),
+        };
+
+        match expr.eval(&d.emu) {
+            Ok(value) if value >= 0 => println!("{} = {} (${:X})", expr, value, value),
+            Ok(value) => println!("{} = {}", expr, value),
+            Err(e) => eprintln!("Could not evaluate \"{}\": {}", expr, e),
+        }
+
+        Ok(())
+    }
+
+    // usage: x [cpu|ppu|oam] <addr> [len]
+    pub fn examine(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        let (space, i) = space_arg(args)?;
+        let addr = space_addr_arg(args, i, space)?;
+        let len = match args.get(i + 1) {
+            Some(_) => uint_arg(args, i + 1)? as usize,
+            None => 0x80,
+        };
+
+        hex_print(&d.read_mem(space), addr as usize, len, Some(space.name()));
+
+        Ok(())
+    }
+
+    // usage: poke [cpu|ppu|oam] <addr> <bytes...>
+    pub fn poke(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        let (space, i) = space_arg(args)?;
+        let addr = space_addr_arg(args, i, space)?;
+
+        if args.len() <= i + 1 {
+            return Err(CommandRunError::MissingArgument(i + 1));
+        }
+
+        let data = (i + 1..args.len())
+            .map(|j| byte_arg(args, j))
+            .collect::<Result<Vec<u8>, CommandRunError>>()?;
+
+        if addr as usize + data.len() > space.size() {
+            return Err(CommandRunError::AddressOutOfRange(i, (addr as usize + data.len() - 1) as u32));
+        }
+
+        for (offset, byte) in data.iter().enumerate() {
+            let addr = addr + offset as u16;
+            if !d.write_mem(space, addr, *byte) {
+                return Err(CommandRunError::NotWritable(addr));
+            }
+        }
+
+        Ok(())
+    }
+
+    // usage: fill [cpu|ppu|oam] <addr> <len> <byte>
+    pub fn fill(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        let (space, i) = space_arg(args)?;
+        let addr = space_addr_arg(args, i, space)?;
+        let len = uint_arg(args, i + 1)? as usize;
+        let byte = byte_arg(args, i + 2)?;
+
+        if addr as usize + len > space.size() {
+            return Err(CommandRunError::ValueOutOfRange(i + 1, len as u32));
+        }
+
+        for offset in 0..len {
+            let addr = addr + offset as u16;
+            if !d.write_mem(space, addr, byte) {
+                return Err(CommandRunError::NotWritable(addr));
+            }
+        }
+
+        Ok(())
+    }
+
+    // usage: set <register> = <expr> or set flag <flag> = <expr>
+    pub fn set(d: &mut Debugger, args: &[Arg]) -> Result<(), CommandRunError> {
+        let (target, expr) = match (args.first(), args.get(1)) {
+            (Some(Arg::String(target)), Some(Arg::Expr(expr))) => (target, expr),
+            _ => return Err(CommandRunError::MissingArgument(0)),
+        };
+
+        let (name, flag_only) = match target.strip_prefix("flag ") {
+            Some(name) => (name.trim(), true),
+            None => (target.as_str(), false),
+        };
+
+        let var = match Var::from_name(name) {
+            Some(Var::Flag(bit)) => Var::Flag(bit),
+            Some(var) if !flag_only && matches!(var, Var::A | Var::X | Var::Y | Var::Sp | Var::Pc | Var::P) => var,
+            _ => return Err(CommandRunError::InvalidTarget(target.clone())),
+        };
+
+        let value = match expr.eval(&d.emu) {
+            Ok(value) => value,
+            Err(e) => {
+                eprintln!("Could not evaluate \"{}\": {}", expr, e);
+                return Ok(());
+            }
+        };
+
+        let max = match var {
+            Var::Pc => 0xFFFF,
+            Var::Flag(_) => 1,
+            _ => 0xFF,
+        };
+        if !(0..=max).contains(&value) {
+            return Err(CommandRunError::InvalidAssignment{ target: target.clone(), value });
+        }
+
+        // the CPU already fetched the next opcode, moving the PC has to fetch it again
+        if var == Var::Pc {
+            return if d.emu.set_pc(value as u16) {
+                Ok(())
+            } else {
+                Err(CommandRunError::MidInstruction)
+            };
+        }
+
+        let regs = d.emu.cpu.get_cup_regs_mut().ok_or(CommandRunError::MidInstruction)?;
+        match var {
+            Var::A => regs.a = value as u8,
+            Var::X => regs.x = value as u8,
+            Var::Y => regs.y = value as u8,
+            Var::Sp => regs.sp = value as u8,
+            Var::P => regs.status = value as u8 | 0x20, // bit 5 is always high
+            Var::Flag(bit) => regs.status = regs.status & !(1 << bit) | (value as u8) << bit,
+            _ => {}
         }
 
         Ok(())
@@ -418,8 +1096,8 @@ use std::sync::atomic::Ordering;
 }
 
 pub fn hex_print(bytes: &[u8], start: usize, len: usize, title_text: Option<&str>) {
-    let len = if len > bytes.len() - start as usize {
-        bytes.len() - start as usize
+    let len = if len > bytes.len() - start {
+        bytes.len() - start
     } else {
         len
     };
@@ -432,9 +1110,9 @@ pub fn hex_print(bytes: &[u8], start: usize, len: usize, title_text: Option<&str
     let mut col = 0;
     let mut s = String::new();
 
-    for (i, b) in bytes[start as usize .. start as usize + len].iter().enumerate() {
+    for (i, b) in bytes[start .. start + len].iter().enumerate() {
         if i % 0x10 == 0 {
-            print!("│ {:04X} │ ", start as usize + i);
+            print!("│ {:04X} │ ", start + i);
         }
         
         print!("{:02X} ", b);
@@ -453,7 +1131,8 @@ pub fn hex_print(bytes: &[u8], start: usize, len: usize, title_text: Option<&str
         }
     }
 
-    if col < 0xF {
+    // pad the last row if it isn't full
+    if len % 0x10 != 0 {
         while col < 0xF {
             print!("   ");
             col += 1;
@@ -465,4 +1144,233 @@ pub fn hex_print(bytes: &[u8], start: usize, len: usize, title_text: Option<&str
     }
 
     println!("└──────┴─────────────────────────────────────────────────┴──────────────────┘");
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_one(input: &str) -> Command {
+        match Command::parse(input) {
+            Ok(mut cmds) if cmds.len() == 1 => cmds.remove(0),
+            _ => panic!("\"{}\" did not parse to a single command", input),
+        }
+    }
+
+    // fn pointers are compared by address, the delegates are all distinct functions
+    fn routes_to(input: &str, delegate: CommandDelegate) -> bool {
+        parse_one(input).delegate as usize == delegate as usize
+    }
+
+    #[test]
+    fn short_names_dont_shadow_longer_commands() {
+        assert!(routes_to("s", commands::step));
+        assert!(routes_to("step 3", commands::step));
+        assert!(routes_to("set a = 1", commands::set));
+
+        assert!(routes_to("r", commands::run));
+        assert!(routes_to("run", commands::run));
+        assert!(routes_to("rw $2002 1", commands::add_read_watchpoint));
+        assert!(routes_to("rwatch $2002 1", commands::add_read_watchpoint));
+
+        assert!(routes_to("d 1", commands::delete_breakpoint));
+        assert!(routes_to("delete 1", commands::delete_breakpoint));
+        assert!(routes_to("dis 1", commands::disable_breakpoint));
+        assert!(routes_to("disable 1", commands::disable_breakpoint));
+        assert!(routes_to("en 1", commands::enable_breakpoint));
+
+        assert!(routes_to("p a", commands::print));
+        assert!(routes_to("print a", commands::print));
+        assert!(routes_to("poke $8000 1", commands::poke));
+    }
+
+    #[test]
+    fn other_commands() {
+        assert!(routes_to("c 10", commands::cycle));
+        assert!(routes_to("b $C000", commands::add_breakpoint));
+        assert!(routes_to("w $0300 4", commands::add_write_watchpoint));
+        assert!(routes_to("aw $0300 4", commands::add_access_watchpoint));
+        assert!(routes_to("i b", commands::info_breakpoints));
+        assert!(routes_to("info breakpoints", commands::info_breakpoints));
+        assert!(routes_to("x $0 $10", commands::examine));
+        assert!(routes_to("f $0 $10 $FF", commands::fill));
+    }
+
+    #[test]
+    fn arguments() {
+        let cmd = parse_one("x oam $10 16");
+        assert!(matches!(cmd.args.as_slice(), [Arg::String(s), Arg::UInt(0x10), Arg::UInt(16)] if s == "oam"));
+
+        let cmd = parse_one("set  a = 1 + 2");
+        assert!(matches!(cmd.args.as_slice(), [Arg::String(t), Arg::Expr(_)] if t == "a"));
+
+        // the condition goes after the normal arguments
+        let cmd = parse_one("b $C000 if a == 1");
+        assert!(matches!(cmd.args.as_slice(), [Arg::UInt(0xC000), Arg::Condition(_)]));
+
+        let cmds = Command::parse("s; r").ok().unwrap();
+        assert_eq!(cmds.len(), 2);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(Command::parse(""), Err(CommandParseError::EmptyInput)));
+        assert!(matches!(Command::parse("bogus"), Err(CommandParseError::UnknownCommand)));
+        assert!(matches!(Command::parse("r 1"), Err(CommandParseError::InvalidArgumentNum{expected: 0, got: 1})));
+        assert!(matches!(Command::parse("set a"), Err(CommandParseError::InvalidArgument{index: 0})));
+        assert!(matches!(Command::parse("p 1 +"), Err(CommandParseError::InvalidExpression(_))));
+    }
+
+    #[test]
+    fn display_shows_the_command_as_typed() {
+        assert_eq!(parse_one("  poke $8000 1 ").to_string(), "poke $8000 1");
+        assert_eq!(parse_one("x 0x10 16").to_string(), "x 0x10 16");
+    }
+
+    // LDA #$11, STA $0300, LDA $0300, STA $2000, JMP $800B
+    const PROGRAM: [u8; 14] = [0xA9, 0x11, 0x8D, 0x00, 0x03, 0xAD, 0x00, 0x03, 0x8D, 0x00, 0x20, 0x4C, 0x0B, 0x80];
+
+    // NROM image running the program from $8000, stepped past the reset sequence
+    fn debugger() -> Debugger {
+        let mut image = b"NES\x1A\x01\x01".to_vec();
+        image.resize(16 + 0x4000 + 0x2000, 0);
+        image[16..16 + PROGRAM.len()].copy_from_slice(&PROGRAM);
+        image[16 + 0x3FFD] = 0x80;
+
+        let mut d = Debugger::new(Emulator::new(crate::cartridge::Cartridge::read(&image[..]).unwrap()));
+        d.step().unwrap();
+        assert_eq!(d.emu.cpu.get_cpu_regs().pc, 0x8000);
+        d
+    }
+
+    fn command(d: &mut Debugger, input: &str) {
+        let cmd = parse_one(input);
+        assert!((cmd.delegate)(d, &cmd.args).is_ok(), "\"{}\" failed", input);
+    }
+
+    // cycles until a watchpoint or breakpoint stops the program like the run command does,
+    // false if nothing stopped it in a few times the length of the program
+    fn run_until_stop(d: &mut Debugger) -> bool {
+        for _ in 0..100 {
+            d.cycle().unwrap();
+            if d.watch_hit.is_some() || d.breakpoint_hit().is_some() {
+                return true;
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn watchpoint_matches() {
+        let access = |kind, addr, data| BusAccess { kind, addr, old: None, data };
+        let mut w = Watchpoint { id: 1, kind: WatchKind::Write, start: 0x0300, end: 0x0301, value: None, condition: None, enabled: true };
+
+        // the range is inclusive
+        assert!(w.matches(&access(BusAccessKind::Write, 0x0300, 0)));
+        assert!(w.matches(&access(BusAccessKind::Write, 0x0301, 0)));
+        assert!(!w.matches(&access(BusAccessKind::Write, 0x02FF, 0)));
+        assert!(!w.matches(&access(BusAccessKind::Write, 0x0302, 0)));
+
+        assert!(!w.matches(&access(BusAccessKind::Read, 0x0300, 0)));
+        w.kind = WatchKind::Read;
+        assert!(w.matches(&access(BusAccessKind::Read, 0x0300, 0)));
+        assert!(!w.matches(&access(BusAccessKind::Write, 0x0300, 0)));
+        w.kind = WatchKind::Access;
+        assert!(w.matches(&access(BusAccessKind::Read, 0x0300, 0)));
+        assert!(w.matches(&access(BusAccessKind::Write, 0x0300, 0)));
+
+        w.value = Some(0x11);
+        assert!(w.matches(&access(BusAccessKind::Write, 0x0300, 0x11)));
+        assert!(!w.matches(&access(BusAccessKind::Write, 0x0300, 0x12)));
+
+        w.enabled = false;
+        assert!(!w.matches(&access(BusAccessKind::Write, 0x0300, 0x11)));
+    }
+
+    #[test]
+    fn write_watchpoints() {
+        let mut d = debugger();
+        d.write_mem(MemSpace::Cpu, 0x0300, 0x22);
+        command(&mut d, "w $0300");
+
+        // the hit reports the start of the instruction and the value before the write
+        assert!(run_until_stop(&mut d));
+        let hit = d.take_watch_hit().unwrap();
+        assert_eq!((hit.id, hit.pc), (1, 0x8002));
+        assert_eq!(hit.access.kind, BusAccessKind::Write);
+        assert_eq!((hit.access.addr, hit.access.old, hit.access.data), (0x0300, Some(0x22), 0x11));
+
+        // the read by LDA doesn't trip a write watchpoint
+        assert!(!run_until_stop(&mut d));
+
+        // registers have no old value
+        let mut d = debugger();
+        command(&mut d, "w $2000-$2007");
+        assert!(run_until_stop(&mut d));
+        let hit = d.take_watch_hit().unwrap();
+        assert_eq!(hit.pc, 0x8008);
+        assert_eq!((hit.access.addr, hit.access.old, hit.access.data), (0x2000, None, 0x11));
+    }
+
+    #[test]
+    fn read_watchpoints_values_and_conditions() {
+        let mut d = debugger();
+        command(&mut d, "rw $0300 $12");
+        command(&mut d, "aw $0300 if a == 5");
+        command(&mut d, "rw $0300 $11");
+        assert!(run_until_stop(&mut d));
+        let hit = d.take_watch_hit().unwrap();
+        assert_eq!((hit.id, hit.pc), (3, 0x8005));
+        assert_eq!(hit.access.kind, BusAccessKind::Read);
+        assert_eq!((hit.access.old, hit.access.data), (None, 0x11));
+
+        // disabled watchpoints don't trip
+        let mut d = debugger();
+        command(&mut d, "aw $0300 if a == $11");
+        command(&mut d, "dis 1");
+        assert!(!run_until_stop(&mut d));
+        command(&mut d, "en 1");
+        d.step().unwrap();
+        command(&mut d, "set pc = $8000");
+        assert!(run_until_stop(&mut d));
+        assert_eq!(d.take_watch_hit().unwrap().pc, 0x8002);
+    }
+
+    #[test]
+    fn breakpoints() {
+        let mut d = debugger();
+        command(&mut d, "b $8005 if a == 2");
+        command(&mut d, "b $8008");
+        command(&mut d, "dis 2");
+        command(&mut d, "b $8008 if a == $11");
+
+        assert!(run_until_stop(&mut d));
+        assert_eq!(d.breakpoint_hit().unwrap().id, 3);
+        assert_eq!(d.emu.cpu.get_cpu_regs().pc, 0x8008);
+
+        // only checked between instructions
+        d.cycle().unwrap();
+        assert!(d.breakpoint_hit().is_none());
+        assert!(!run_until_stop(&mut d));
+    }
+
+    #[test]
+    fn memory_space_goes_first() {
+        let mut d = debugger();
+        let run = |d: &mut Debugger, input: &str| {
+            let cmd = parse_one(input);
+            (cmd.delegate)(d, &cmd.args)
+        };
+
+        assert!(run(&mut d, "poke oam $10 1 2").is_ok());
+        assert_eq!(d.read_mem(MemSpace::Oam)[0x10..0x12], [1, 2]);
+        assert!(run(&mut d, "fill ppu $2000 4 $FF").is_ok());
+        assert_eq!(d.read_mem(MemSpace::Ppu)[0x2003], 0xFF);
+        assert!(run(&mut d, "x oam $10 16").is_ok());
+
+        assert!(matches!(run(&mut d, "x $10 16 oam"), Err(CommandRunError::MisplacedSpace(2))));
+        assert!(matches!(run(&mut d, "poke $10 1 OAM"), Err(CommandRunError::MisplacedSpace(2))));
+        assert!(matches!(run(&mut d, "fill $0 1 2 ppu"), Err(CommandRunError::MisplacedSpace(3))));
+        assert!(matches!(run(&mut d, "x ppu $10 cpu"), Err(CommandRunError::MisplacedSpace(2))));
+    }
+}
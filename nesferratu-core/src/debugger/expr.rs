@@ -0,0 +1,534 @@
+use std::fmt::Display;
+
+use crate::Emulator;
+use crate::debugger::CpuDebugger;
+
+// small expression language for breakpoint conditions and the print command, e.g.
+// a == $40 && [$0300] > 3 && cycles > 100000
+//
+// numbers:     42, $2A, 0x2A, %101010, 0b101010
+// registers:   a, x, y, sp, pc, p
+// flags:       n, v, b, d, i, z, c (0 or 1)
+// emulation:   cycles, op_cycle, scanline, dot
+// memory:      [addr] reads a byte from the CPU bus without side effects
+// operators:   ! ~ - (unary), * / %, + -, << >>, &, ^, |, comparisons, &&, ||
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprError {
+    Parse { pos: usize, msg: String },  // byte offset into the source
+    DivisionByZero,
+}
+
+impl Display for ExprError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExprError::Parse { pos, msg } => write!(f, "{} at position {}", msg, pos + 1),
+            ExprError::DivisionByZero => write!(f, "Division by zero"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Var {
+    A,
+    X,
+    Y,
+    Sp,
+    Pc,
+    P,
+    Flag(u8),   // bit in the status register
+    Cycles,
+    OpCycle,
+    Scanline,
+    Dot,
+}
+
+impl Var {
+    pub(crate) fn from_name(name: &str) -> Option<Var> {
+        let var = match name.to_ascii_lowercase().as_str() {
+            "a" => Var::A,
+            "x" => Var::X,
+            "y" => Var::Y,
+            "sp" | "s" => Var::Sp,
+            "pc" => Var::Pc,
+            "p" | "status" => Var::P,
+            "n" => Var::Flag(7),
+            "v" => Var::Flag(6),
+            "b" => Var::Flag(4),
+            "d" => Var::Flag(3),
+            "i" => Var::Flag(2),
+            "z" => Var::Flag(1),
+            "c" => Var::Flag(0),
+            "cycles" => Var::Cycles,
+            "op_cycle" => Var::OpCycle,
+            "scanline" => Var::Scanline,
+            "dot" => Var::Dot,
+            _ => return None,
+        };
+        Some(var)
+    }
+
+    fn eval(&self, emu: &Emulator) -> i64 {
+        let regs = emu.cpu.get_cpu_regs();
+        let state = emu.cpu.get_emulation_state();
+
+        match self {
+            Var::A => regs.a as i64,
+            Var::X => regs.x as i64,
+            Var::Y => regs.y as i64,
+            Var::Sp => regs.sp as i64,
+            Var::Pc => regs.pc as i64,
+            Var::P => regs.status as i64,
+            Var::Flag(bit) => (regs.status >> bit & 1) as i64,
+            Var::Cycles => state.total_cycles as i64,
+            Var::OpCycle => state.op_cycle as i64,
+            Var::Scanline => emu.ppu.scanline() as i64,
+            Var::Dot => emu.ppu.dot() as i64,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum UnOp {
+    Not,
+    BitNot,
+    Neg,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinOp {
+    Mul,
+    Div,
+    Rem,
+    Add,
+    Sub,
+    Shl,
+    Shr,
+    BitAnd,
+    BitXor,
+    BitOr,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+impl BinOp {
+    // higher binds tighter
+    fn precedence(&self) -> u8 {
+        match self {
+            BinOp::Mul | BinOp::Div | BinOp::Rem => 9,
+            BinOp::Add | BinOp::Sub => 8,
+            BinOp::Shl | BinOp::Shr => 7,
+            BinOp::BitAnd => 6,
+            BinOp::BitXor => 5,
+            BinOp::BitOr => 4,
+            BinOp::Lt | BinOp::Le | BinOp::Gt | BinOp::Ge => 3,
+            BinOp::Eq | BinOp::Ne => 2,
+            BinOp::And => 1,
+            BinOp::Or => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Num(i64),
+    Var(Var),
+    Mem(Box<Node>),
+    Unary(UnOp, Box<Node>),
+    Binary(BinOp, Box<Node>, Box<Node>),
+}
+
+impl Node {
+    fn eval(&self, emu: &Emulator) -> Result<i64, ExprError> {
+        let value = match self {
+            Node::Num(n) => *n,
+            Node::Var(v) => v.eval(emu),
+            Node::Mem(addr) => emu.peek_cpu(addr.eval(emu)? as u16) as i64,
+            Node::Unary(op, e) => {
+                let v = e.eval(emu)?;
+                match op {
+                    UnOp::Not => (v == 0) as i64,
+                    UnOp::BitNot => !v,
+                    UnOp::Neg => v.wrapping_neg(),
+                }
+            }
+            // both sides of && and || are evaluated, there are no side effects to skip
+            Node::Binary(op, l, r) => {
+                let l = l.eval(emu)?;
+                let r = r.eval(emu)?;
+                match op {
+                    BinOp::Mul => l.wrapping_mul(r),
+                    BinOp::Div | BinOp::Rem if r == 0 => return Err(ExprError::DivisionByZero),
+                    BinOp::Div => l.wrapping_div(r),
+                    BinOp::Rem => l.wrapping_rem(r),
+                    BinOp::Add => l.wrapping_add(r),
+                    BinOp::Sub => l.wrapping_sub(r),
+                    BinOp::Shl => l.wrapping_shl(r as u32),
+                    BinOp::Shr => l.wrapping_shr(r as u32),
+                    BinOp::BitAnd => l & r,
+                    BinOp::BitXor => l ^ r,
+                    BinOp::BitOr => l | r,
+                    BinOp::Eq => (l == r) as i64,
+                    BinOp::Ne => (l != r) as i64,
+                    BinOp::Lt => (l < r) as i64,
+                    BinOp::Le => (l <= r) as i64,
+                    BinOp::Gt => (l > r) as i64,
+                    BinOp::Ge => (l >= r) as i64,
+                    BinOp::And => (l != 0 && r != 0) as i64,
+                    BinOp::Or => (l != 0 || r != 0) as i64,
+                }
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+// parsed expression together with its source text for display
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    source: String,
+    root: Node,
+}
+
+impl Expr {
+    pub fn parse(source: &str) -> Result<Expr, ExprError> {
+        let mut parser = Parser { src: source.as_bytes(), pos: 0 };
+
+        let root = parser.expr(0)?;
+        parser.skip_whitespace();
+
+        if parser.pos < parser.src.len() {
+            return Err(parser.error("Unexpected input"));
+        }
+
+        Ok(Expr { source: source.trim().to_owned(), root })
+    }
+
+    pub fn eval(&self, emu: &Emulator) -> Result<i64, ExprError> {
+        self.root.eval(emu)
+    }
+
+    // conditions are true when not zero
+    pub fn is_true(&self, emu: &Emulator) -> Result<bool, ExprError> {
+        Ok(self.eval(emu)? != 0)
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+// precedence climbing parser working directly on the source bytes
+struct Parser<'a> {
+    src: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, msg: &str) -> ExprError {
+        ExprError::Parse { pos: self.pos, msg: msg.to_owned() }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.src.len() && self.src[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.get(self.pos).copied()
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.src[self.pos..].starts_with(s.as_bytes())
+    }
+
+    // next binary operator and its length in bytes
+    fn binary_op(&mut self) -> Option<(BinOp, usize)> {
+        self.skip_whitespace();
+
+        // longer operators first so && isn't read as &
+        let ops = [
+            ("&&", BinOp::And), ("||", BinOp::Or),
+            ("==", BinOp::Eq), ("!=", BinOp::Ne), ("<=", BinOp::Le), (">=", BinOp::Ge),
+            ("<<", BinOp::Shl), (">>", BinOp::Shr),
+            ("<", BinOp::Lt), (">", BinOp::Gt),
+            ("*", BinOp::Mul), ("/", BinOp::Div), ("%", BinOp::Rem),
+            ("+", BinOp::Add), ("-", BinOp::Sub),
+            ("&", BinOp::BitAnd), ("^", BinOp::BitXor), ("|", BinOp::BitOr),
+        ];
+
+        ops.iter().find(|(s, _)| self.starts_with(s)).map(|(s, op)| (*op, s.len()))
+    }
+
+    fn expr(&mut self, min_precedence: u8) -> Result<Node, ExprError> {
+        let mut lhs = self.unary()?;
+
+        while let Some((op, len)) = self.binary_op() {
+            if op.precedence() < min_precedence {
+                break;
+            }
+
+            self.pos += len;
+            let rhs = self.expr(op.precedence() + 1)?;
+            lhs = Node::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Node, ExprError> {
+        self.skip_whitespace();
+
+        let op = match self.peek() {
+            Some(b'!') if !self.starts_with("!=") => UnOp::Not,
+            Some(b'~') => UnOp::BitNot,
+            Some(b'-') => UnOp::Neg,
+            _ => return self.primary(),
+        };
+
+        self.pos += 1;
+        Ok(Node::Unary(op, Box::new(self.unary()?)))
+    }
+
+    fn primary(&mut self) -> Result<Node, ExprError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(b'(') => {
+                self.pos += 1;
+                let e = self.expr(0)?;
+                self.expect(b')')?;
+                Ok(e)
+            }
+            Some(b'[') => {
+                self.pos += 1;
+                let e = self.expr(0)?;
+                self.expect(b']')?;
+                Ok(Node::Mem(Box::new(e)))
+            }
+            Some(b'$') => {
+                self.pos += 1;
+                self.number(16)
+            }
+            Some(b'%') => {
+                self.pos += 1;
+                self.number(2)
+            }
+            Some(b'0') if self.starts_with("0x") || self.starts_with("0X") => {
+                self.pos += 2;
+                self.number(16)
+            }
+            Some(b'0') if self.starts_with("0b") || self.starts_with("0B") => {
+                self.pos += 2;
+                self.number(2)
+            }
+            Some(c) if c.is_ascii_digit() => self.number(10),
+            Some(c) if c.is_ascii_alphabetic() || c == b'_' => {
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == b'_') {
+                    self.pos += 1;
+                }
+
+                // the source is valid UTF-8 and identifiers are ASCII
+                let name = std::str::from_utf8(&self.src[start..self.pos]).unwrap_or_default();
+                match Var::from_name(name) {
+                    Some(var) => Ok(Node::Var(var)),
+                    None => {
+                        self.pos = start;
+                        Err(self.error(&format!("Unknown variable \"{}\"", name)))
+                    }
+                }
+            }
+            Some(_) => Err(self.error("Unexpected character")),
+            None => Err(self.error("Unexpected end of expression")),
+        }
+    }
+
+    fn number(&mut self, radix: u32) -> Result<Node, ExprError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| (c as char).is_digit(radix)) {
+            self.pos += 1;
+        }
+
+        if start == self.pos {
+            return Err(self.error("Expected a number"));
+        }
+
+        let digits = std::str::from_utf8(&self.src[start..self.pos]).unwrap_or_default();
+        match i64::from_str_radix(digits, radix) {
+            Ok(n) => Ok(Node::Num(n)),
+            Err(_) => {
+                self.pos = start;
+                Err(self.error("Number too large"))
+            }
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), ExprError> {
+        self.skip_whitespace();
+
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{}'", c as char)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartridge::Cartridge;
+
+    // NROM image of zeros, clocked until the reset sequence is done so the registers can be set
+    fn emulator() -> Emulator {
+        let mut image = b"NES\x1A\x01\x01".to_vec();
+        image.resize(16 + 0x4000 + 0x2000, 0);
+
+        let mut emu = Emulator::new(Cartridge::read(&image[..]).unwrap());
+        while emu.cpu.get_cup_regs_mut().is_none() {
+            emu.clock().unwrap();
+        }
+        emu
+    }
+
+    fn root(source: &str) -> Node {
+        Expr::parse(source).unwrap().root
+    }
+
+    fn eval(source: &str) -> Result<i64, ExprError> {
+        Expr::parse(source).unwrap().eval(&emulator())
+    }
+
+    fn parse_error(source: &str) -> (usize, String) {
+        match Expr::parse(source) {
+            Err(ExprError::Parse { pos, msg }) => (pos, msg),
+            other => panic!("expected a parse error for {:?}, got {:?}", source, other),
+        }
+    }
+
+    fn num(n: i64) -> Box<Node> {
+        Box::new(Node::Num(n))
+    }
+
+    fn var(v: Var) -> Box<Node> {
+        Box::new(Node::Var(v))
+    }
+
+    fn bin(op: BinOp, l: Box<Node>, r: Box<Node>) -> Box<Node> {
+        Box::new(Node::Binary(op, l, r))
+    }
+
+    #[test]
+    fn precedence() {
+        let expected = bin(
+            BinOp::And,
+            bin(BinOp::Eq, var(Var::A), num(0x40)),
+            bin(BinOp::Gt, Box::new(Node::Mem(num(0x0300))), num(3)),
+        );
+        assert_eq!(root("a == $40 && [$0300] > 3"), *expected);
+
+        assert_eq!(root("1 + 2 * 3"), *bin(BinOp::Add, num(1), bin(BinOp::Mul, num(2), num(3))));
+        assert_eq!(root("(1 + 2) * 3"), *bin(BinOp::Mul, bin(BinOp::Add, num(1), num(2)), num(3)));
+        assert_eq!(root("1 | 2 & 3"), *bin(BinOp::BitOr, num(1), bin(BinOp::BitAnd, num(2), num(3))));
+        assert_eq!(root("1 || 0 && 0"), *bin(BinOp::Or, num(1), bin(BinOp::And, num(0), num(0))));
+
+        // operators of the same precedence are left associative
+        assert_eq!(root("8 - 4 - 2"), *bin(BinOp::Sub, bin(BinOp::Sub, num(8), num(4)), num(2)));
+        assert_eq!(eval("8 - 4 - 2"), Ok(2));
+        assert_eq!(eval("1 << 2 + 1"), Ok(8));
+    }
+
+    #[test]
+    fn literals() {
+        for source in ["42", "$2A", "$2a", "0x2A", "0X2a", "%101010", "0b101010", "0B101010"] {
+            assert_eq!(root(source), Node::Num(42), "{}", source);
+        }
+    }
+
+    #[test]
+    fn percent_is_binary_literal_or_remainder() {
+        assert_eq!(root("%101"), Node::Num(5));
+        assert_eq!(root("7 % 2"), *bin(BinOp::Rem, num(7), num(2)));
+        assert_eq!(root("7 %10"), *bin(BinOp::Rem, num(7), num(10)));
+        assert_eq!(root("%10 % %11"), *bin(BinOp::Rem, num(2), num(3)));
+        assert_eq!(eval("%110 % 4"), Ok(2));
+    }
+
+    #[test]
+    fn not_and_not_equal() {
+        assert_eq!(root("!a"), Node::Unary(UnOp::Not, var(Var::A)));
+        assert_eq!(root("a != 1"), *bin(BinOp::Ne, var(Var::A), num(1)));
+        assert_eq!(root("a!=!1"), *bin(BinOp::Ne, var(Var::A), Box::new(Node::Unary(UnOp::Not, num(1)))));
+        assert_eq!(eval("!0"), Ok(1));
+        assert_eq!(eval("!!5"), Ok(1));
+        assert_eq!(eval("~0"), Ok(-1));
+        assert_eq!(parse_error("!= 1").0, 0);
+    }
+
+    #[test]
+    fn variables_and_memory() {
+        let mut emu = emulator();
+        emu.cpu.get_cup_regs_mut().unwrap().a = 0x40;
+        emu.cpu.get_cup_regs_mut().unwrap().status = 0x81;
+        emu.poke_cpu(0x0300, 5);
+
+        let eval = |source: &str| Expr::parse(source).unwrap().eval(&emu);
+        assert_eq!(eval("a == $40 && [$0300] > 3"), Ok(1));
+        assert_eq!(eval("[$0300 + $800]"), Ok(5));  // RAM mirror
+        assert_eq!(eval("A + X"), Ok(0x40));
+        assert_eq!(eval("n + c + z"), Ok(2));
+        assert_eq!(eval("p"), Ok(0x81));
+    }
+
+    #[test]
+    fn error_positions() {
+        assert_eq!(parse_error(""), (0, String::from("Unexpected end of expression")));
+        assert_eq!(parse_error("a + "), (4, String::from("Unexpected end of expression")));
+        assert_eq!(parse_error("a + foo"), (4, String::from("Unknown variable \"foo\"")));
+        assert_eq!(parse_error("(1 + 2"), (6, String::from("Expected ')'")));
+        assert_eq!(parse_error("[1"), (2, String::from("Expected ']'")));
+        assert_eq!(parse_error("1 2"), (2, String::from("Unexpected input")));
+        assert_eq!(parse_error("$"), (1, String::from("Expected a number")));
+        assert_eq!(parse_error("a # 1"), (2, String::from("Unexpected input")));
+
+        // positions are shown 1-based
+        assert_eq!(Expr::parse("a + ").unwrap_err().to_string(), "Unexpected end of expression at position 5");
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(parse_error("$10000000000000000"), (1, String::from("Number too large")));
+        assert_eq!(parse_error("1 + 9223372036854775808"), (4, String::from("Number too large")));
+        assert_eq!(root("9223372036854775807"), Node::Num(i64::MAX));
+
+        // arithmetic wraps around instead of failing
+        assert_eq!(eval("$7FFFFFFFFFFFFFFF + 1"), Ok(i64::MIN));
+        assert_eq!(eval("-$7FFFFFFFFFFFFFFF - 2"), Ok(i64::MAX));
+        assert_eq!(eval("$7FFFFFFFFFFFFFFF * 2"), Ok(-2));
+        assert_eq!(eval("(-$7FFFFFFFFFFFFFFF - 1) / -1"), Ok(i64::MIN));
+        assert_eq!(eval("(-$7FFFFFFFFFFFFFFF - 1) % -1"), Ok(0));
+        assert_eq!(eval("-(-$7FFFFFFFFFFFFFFF - 1)"), Ok(i64::MIN));
+        assert_eq!(eval("1 << 64"), Ok(1));
+    }
+
+    #[test]
+    fn division_by_zero() {
+        assert_eq!(eval("1 / 0"), Err(ExprError::DivisionByZero));
+        assert_eq!(eval("1 % 0"), Err(ExprError::DivisionByZero));
+        assert_eq!(eval("1 / (a - a)"), Err(ExprError::DivisionByZero));
+        assert_eq!(eval("7 / 2"), Ok(3));
+        assert_eq!(eval("-7 % 2"), Ok(-1));
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::io::{BufRead, Result as IoResult};
+
+use num_traits::FromPrimitive;
+
+use crate::Emulator;
+use crate::cpu::CpuError;
+use crate::cpu::instructions::Opcode;
+use crate::debugger::CpuDebugger;
+
+// nestest.nes runs all of its tests without a PPU when started at $C000
+pub const NESTEST_START: u16 = 0xC000;
+
+// clocks without reaching the next instruction after which the CPU is considered stuck
+const MAX_INSTRUCTION_CLOCKS: usize = 10000;
+
+// writes one line per instruction in the format of Nintendulator, as used by nestest.log
+// C000  4C F5 C5  JMP $C5F5                       A:00 X:00 Y:00 P:24 SP:FD PPU:  0, 21 CYC:7
+pub struct Tracer {
+    // position at the start of the last clock, the cycle in which the next opcode is fetched
+    cycle: u64,
+    scanline: u16,
+    dot: u16,
+}
+
+impl Default for Tracer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tracer {
+    pub fn new() -> Tracer {
+        Tracer {
+            cycle: 0,
+            scanline: 0,
+            dot: 0,
+        }
+    }
+
+    fn clock(&mut self, emu: &mut Emulator) -> Result<(), CpuError> {
+        self.cycle = emu.cycles;
+        self.scanline = emu.ppu.scanline();
+        self.dot = emu.ppu.dot();
+
+        emu.clock()
+    }
+
+    // runs the emulator until the current instruction is done, returns false if the CPU is stuck
+    fn run_instruction(&mut self, emu: &mut Emulator) -> bool {
+        for _ in 0..MAX_INSTRUCTION_CLOCKS {
+            if self.clock(emu).is_err() {
+                return false;
+            }
+
+            if emu.cpu.get_emulation_state().instruction_done {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    // finishes the reset sequence and starts execution at the given address
+    pub fn start(&mut self, emu: &mut Emulator, pc: u16) -> bool {
+        self.run_instruction(emu) && emu.set_pc(pc)
+    }
+
+    // executes the next instruction and returns its trace line, None if the CPU is stuck
+    pub fn step(&mut self, emu: &mut Emulator) -> Option<String> {
+        loop {
+            let line = self.line(emu);
+
+            if !self.run_instruction(emu) {
+                return None;
+            }
+
+            // interrupts have no bytes and don't get their own line, the instruction at PC didn't run yet
+            if let (Some(ins), _) = emu.cpu.get_decoded_instruction() {
+                if ins.bytes > 0 {
+                    return Some(line);
+                }
+            }
+        }
+    }
+
+    // trace line for the instruction at PC, only valid between two instructions
+    pub fn line(&self, emu: &Emulator) -> String {
+        let regs = emu.cpu.get_cpu_regs();
+        let pc = regs.pc;
+
+        let ins = Opcode::from_u8(emu.peek_cpu(pc))
+            .expect("the opcode table covers all 256 opcodes")
+            .to_instruction();
+
+        let raw: Vec<u8> = (0..ins.bytes as u16)
+            .map(|i| emu.peek_cpu(pc.wrapping_add(i)))
+            .collect();
+
+        let bytes: Vec<String> = raw.iter().map(|b| format!("{:02X}", b)).collect();
+
+        let o1 = raw.get(1).copied().unwrap_or(0);
+        let abs = u16::from_le_bytes([o1, raw.get(2).copied().unwrap_or(0)]);
+
+        let peek = |addr: u16| emu.peek_cpu(addr);
+        let peek16_zp = |addr: u8| u16::from_le_bytes([peek(addr as u16), peek(addr.wrapping_add(1) as u16)]);
+
+        let operand = match ins.addressing {
+            "Accum" => String::from(" A"),
+            "IMM" => format!(" #${:02X}", o1),
+            "ZP" => format!(" ${:02X} = {:02X}", o1, peek(o1 as u16)),
+            "ZP, X" => {
+                let addr = o1.wrapping_add(regs.x);
+                format!(" ${:02X},X @ {:02X} = {:02X}", o1, addr, peek(addr as u16))
+            }
+            "ZP, Y" => {
+                let addr = o1.wrapping_add(regs.y);
+                format!(" ${:02X},Y @ {:02X} = {:02X}", o1, addr, peek(addr as u16))
+            }
+            "Absolute" if ins.mnemonic == "JMP" || ins.mnemonic == "JSR" => format!(" ${:04X}", abs),
+            "Absolute" => format!(" ${:04X} = {:02X}", abs, peek(abs)),
+            "ABS, X" => {
+                let addr = abs.wrapping_add(regs.x as u16);
+                format!(" ${:04X},X @ {:04X} = {:02X}", abs, addr, peek(addr))
+            }
+            "ABS, Y" => {
+                let addr = abs.wrapping_add(regs.y as u16);
+                format!(" ${:04X},Y @ {:04X} = {:02X}", abs, addr, peek(addr))
+            }
+            "(IND, X)" => {
+                let pointer = o1.wrapping_add(regs.x);
+                let addr = peek16_zp(pointer);
+                format!(" (${:02X},X) @ {:02X} = {:04X} = {:02X}", o1, pointer, addr, peek(addr))
+            }
+            "(IND), Y" => {
+                let base = peek16_zp(o1);
+                let addr = base.wrapping_add(regs.y as u16);
+                format!(" (${:02X}),Y = {:04X} @ {:04X} = {:02X}", o1, base, addr, peek(addr))
+            }
+            "Indirect" => {
+                // the pointer doesn't carry into its high byte
+                let hi = (abs & 0xFF00) | (abs.wrapping_add(1) & 0x00FF);
+                let target = u16::from_le_bytes([peek(abs), peek(hi)]);
+                format!(" (${:04X}) = {:04X}", abs, target)
+            }
+            "Relative" => {
+                let target = pc.wrapping_add(2).wrapping_add(o1 as i8 as u16);
+                format!(" ${:04X}", target)
+            }
+            _ => String::new(),
+        };
+
+        // Nintendulator calls ISC ISB
+        let mnemonic = match ins.mnemonic {
+            "ISC" => "ISB",
+            m => m,
+        };
+        let disasm = format!("{}{}", mnemonic, operand);
+
+        format!(
+            "{:04X}  {:<8} {}{:<32}A:{:02X} X:{:02X} Y:{:02X} P:{:02X} SP:{:02X} PPU:{:>3},{:>3} CYC:{}",
+            pc,
+            bytes.join(" "),
+            if ins.unofficial { '*' } else { ' ' },
+            disasm,
+            regs.a,
+            regs.x,
+            regs.y,
+            regs.status,
+            regs.sp,
+            self.scanline,
+            self.dot,
+            self.cycle,
+        )
+    }
+}
+
+// first line where the trace differs from the golden log
+pub struct Divergence {
+    pub line: usize,            // line number in the golden log, starting at 1
+    pub context: Vec<String>,   // matching lines right before the divergence
+    pub expected: String,
+    pub got: Option<String>,    // None if the CPU got stuck
+    pub jam: Option<CpuError>,  // why the CPU got stuck, None if it just didn't finish the instruction
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Trace diverges from the golden log at line {}", self.line)?;
+
+        for line in self.context.iter() {
+            writeln!(f, "  {}", line)?;
+        }
+
+        writeln!(f, "- {}", self.expected)?;
+        match &self.got {
+            Some(line) => write!(f, "+ {}", line),
+            None => match &self.jam {
+                Some(e) => write!(f, "+ ({})", e),
+                None => write!(f, "+ (CPU stuck, no instruction finished)"),
+            },
+        }
+    }
+}
+
+pub enum TraceComparison {
+    Match(usize),   // number of compared lines
+    Diverged(Divergence),
+}
+
+// runs the emulator from the given address and compares its trace line by line against a golden log
+pub fn compare_log<R: BufRead>(emu: &mut Emulator, start: u16, golden: R, context: usize) -> IoResult<TraceComparison> {
+    let mut tracer = Tracer::new();
+    let mut history = VecDeque::with_capacity(context);
+    let mut count = 0;
+
+    let started = tracer.start(emu, start);
+
+    for (i, expected) in golden.lines().enumerate() {
+        let expected = expected?;
+        let expected = expected.trim_end();
+
+        if expected.is_empty() {
+            continue;
+        }
+
+        let got = if started { tracer.step(emu) } else { None };
+
+        if got.as_deref().map(str::trim_end) != Some(expected) {
+            return Ok(TraceComparison::Diverged(Divergence {
+                line: i + 1,
+                context: history.into_iter().collect(),
+                expected: expected.to_owned(),
+                got,
+                jam: emu.cpu_jam(),
+            }));
+        }
+
+        if context > 0 {
+            if history.len() >= context {
+                history.pop_front();
+            }
+            history.push_back(expected.to_owned());
+        }
+
+        count += 1;
+    }
+
+    Ok(TraceComparison::Match(count))
+}
@@ -0,0 +1,102 @@
+use crate::BusMessage;
+
+// OAM DMA unit, copies a page of CPU memory to $2004 while the CPU is halted
+#[derive(Default)]
+pub struct OamDma {
+    active: bool,
+    page: u8,
+    index: u16,
+    wait_cycles: u8,
+    read_next: bool,
+    data: u8,
+}
+
+impl OamDma {
+    pub fn new() -> OamDma {
+        OamDma::default()
+    }
+
+    // a transfer takes 513 cycles, plus one alignment cycle if it starts on an odd CPU cycle
+    pub fn start(&mut self, page: u8, odd_cycle: bool) {
+        self.active = true;
+        self.page = page;
+        self.index = 0;
+        self.wait_cycles = if odd_cycle { 2 } else { 1 };
+        self.read_next = true;
+    }
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn clock(&mut self, data: Option<u8>) -> BusMessage {
+        if let Some(data) = data {
+            self.data = data;
+        }
+
+        // halt and alignment cycles
+        if self.wait_cycles > 0 {
+            self.wait_cycles -= 1;
+            return BusMessage::Nop;
+        }
+
+        if self.read_next {
+            self.read_next = false;
+            BusMessage::Read{addr: (self.page as u16) << 8 | self.index}
+        } else {
+            self.read_next = true;
+            self.index += 1;
+            if self.index == 256 {
+                self.active = false;
+            }
+            BusMessage::Write{addr: 0x2004, data: self.data}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // runs a transfer from a page filled with the low byte of the address, returns the number of cycles and the bytes written
+    fn transfer(odd_cycle: bool) -> (usize, Vec<u8>) {
+        let mut dma = OamDma::new();
+        dma.start(0x02, odd_cycle);
+
+        let mut cycles = 0;
+        let mut data = None;
+        let mut written = Vec::new();
+
+        while dma.active() {
+            cycles += 1;
+            data = match dma.clock(data) {
+                BusMessage::Read{addr} => {
+                    assert_eq!(addr >> 8, 0x02);
+                    Some(addr as u8)
+                }
+                BusMessage::Write{addr, data} => {
+                    assert_eq!(addr, 0x2004);
+                    written.push(data);
+                    None
+                }
+                BusMessage::Nop => None,
+            };
+        }
+
+        (cycles, written)
+    }
+
+    #[test]
+    fn even_cycle() {
+        let (cycles, written) = transfer(false);
+        assert_eq!(cycles, 513);
+        assert_eq!(written, (0..=255).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn odd_cycle() {
+        let (cycles, written) = transfer(true);
+        assert_eq!(cycles, 514);
+        assert_eq!(written, (0..=255).collect::<Vec<u8>>());
+    }
+}
@@ -3,12 +3,23 @@ extern crate num_derive;
 extern crate lazy_static;
 
 pub mod cpu;
+pub mod ppu;
+pub mod apu;
+pub mod controller;
+pub mod dma;
+pub mod palette;
 pub mod cartridge;
 pub mod debugger;
+pub mod test_rom;
 
-use cpu::{CPU, CpuInterpreter};
+use cpu::{CPU, CpuError, CpuInterpreter};
+use ppu::Ppu;
+use apu::Apu;
+use controller::{Controller, ButtonState, Port};
+use dma::OamDma;
+use palette::Palette;
 use cartridge::Cartridge;
-use debugger::MemDebugger;
+use debugger::{CpuDebugger, MemDebugger};
 
 #[derive(Debug)]
 pub enum BusMessage {
@@ -17,11 +28,36 @@ pub enum BusMessage {
     Nop,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BusAccessKind {
+    Read,
+    Write,
+}
+
+// one transfer on the CPU bus, old is the value before a write, None for reads and for
+// I/O or mapper registers where there is no memory to read the previous value from
+#[derive(Debug, Clone, Copy)]
+pub struct BusAccess {
+    pub kind: BusAccessKind,
+    pub addr: u16,
+    pub old: Option<u8>,
+    pub data: u8,
+}
+
 pub struct Emulator {
     fetch: Option<u8>,
+    dma_fetch: Option<u8>,
+    open_bus: u8,
+    cycles: u64,
     cpu: CpuInterpreter,
+    ppu: Ppu,
+    apu: Apu,
+    controllers: [Controller; 2],
+    dma: OamDma,
     memory: Ram,
     cartridge: Cartridge,
+    bus_watch: bool,
+    bus_accesses: Vec<BusAccess>,
 }
 
 impl Emulator {
@@ -29,32 +65,223 @@ impl Emulator {
     pub fn new(cartridge: Cartridge) -> Emulator {
         let mut temp = Emulator {
             fetch: None,
+            dma_fetch: None,
+            open_bus: 0,
+            cycles: 0,
             cpu: CpuInterpreter::new(),
+            ppu: Ppu::new(),
+            apu: Apu::new(),
+            controllers: [Controller::new(), Controller::new()],
+            dma: OamDma::new(),
             memory: Ram::new(),
             cartridge,
+            bus_watch: false,
+            bus_accesses: Vec::new(),
         };
-        temp.cpu.reset();
+        temp.reset();
         temp
     }
 
-    pub fn clock(&mut self) {
-        let msg = self.cpu.clock(self.fetch);
+    // the reset button, RAM and the cartridge keep their contents
+    pub fn reset(&mut self) {
+        self.cpu.reset();
+        self.ppu.reset();
+        self.apu.reset();
+        self.dma = OamDma::new();
+        self.fetch = None;
+        self.dma_fetch = None;
+    }
+
+    // returns the error that jammed the CPU in this cycle, the rest of the system keeps running
+    pub fn clock(&mut self) -> Result<(), CpuError> {
+        let mut result = Ok(());
+        self.bus_accesses.clear();
+
+        if self.dma.active() {
+            // the CPU is halted while the DMA unit is using the bus
+            self.cpu.stall();
+            let msg = self.dma.clock(self.dma_fetch);
+            self.dma_fetch = self.bus_transfer(msg);
+        } else {
+            let msg = match self.cpu.clock(self.fetch) {
+                Ok(msg) => msg,
+                Err(e) => {
+                    result = Err(e);
+                    BusMessage::Nop
+                }
+            };
+            self.fetch = self.bus_transfer(msg);
+        }
 
+        self.cycles += 1;
+
+        // the PPU runs at three times the CPU clock
+        for _ in 0..3 {
+            self.ppu.clock(&mut self.cartridge);
+        }
+
+        if self.ppu.poll_nmi() {
+            self.cpu.nmi();
+        }
+
+        self.apu.clock();
+        self.cartridge.cpu_clock();
+
+        // the DMC fetches its samples from the CPU bus
+        if let Some(addr) = self.apu.dmc_read_request() {
+            let data = self.read_cpu(addr).unwrap_or(0);
+            self.apu.dmc_fill(data);
+        }
+
+        // IRQ is a shared, level triggered line
+        if self.apu.irq() || self.cartridge.irq() {
+            self.cpu.irq();
+        }
+
+        result
+    }
+
+    fn bus_transfer(&mut self, msg: BusMessage) -> Option<u8> {
         match msg {
             BusMessage::Read { addr } => {
-                self.fetch = self.read_cpu(addr);
+                let data = self.read_cpu(addr);
+                if let Some(data) = data {
+                    self.open_bus = data;
+                }
+                if self.bus_watch {
+                    let data = data.unwrap_or(self.open_bus);
+                    self.bus_accesses.push(BusAccess { kind: BusAccessKind::Read, addr, old: None, data });
+                }
+                data
             }
             BusMessage::Write { addr, data } => {
-                self.fetch = None;
+                if self.bus_watch {
+                    let old = self.peek_memory(addr);
+                    self.bus_accesses.push(BusAccess { kind: BusAccessKind::Write, addr, old, data });
+                }
+                self.open_bus = data;
                 self.write_cpu(addr, data);
+                None
             }
-            BusMessage::Nop => {
-                self.fetch = None;
+            BusMessage::Nop => None,
+        }
+    }
+
+    // runs the emulation until the PPU has finished drawing the next frame,
+    // stops early if the CPU jams, a jammed CPU doesn't stop later frames
+    pub fn run_frame(&mut self) -> Result<(), CpuError> {
+        while !self.ppu.poll_frame() {
+            self.clock()?;
+        }
+        Ok(())
+    }
+
+    // the error that stopped the CPU, None while it is running
+    pub fn cpu_jam(&self) -> Option<CpuError> {
+        self.cpu.get_emulation_state().jam
+    }
+
+    pub fn frame_buffer(&self) -> &[u16] {
+        self.ppu.frame_buffer()
+    }
+
+    pub fn frame_rgba(&self, palette: &Palette) -> Vec<u8> {
+        let mut rgba = vec![0u8; ppu::SCREEN_WIDTH * ppu::SCREEN_HEIGHT * 4];
+        palette.to_rgba(self.ppu.frame_buffer(), &mut rgba);
+        rgba
+    }
+
+    // audio is off until a sample rate is set
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.apu.set_sample_rate(sample_rate);
+    }
+
+    // returns the audio samples generated since the last call, at most one second's worth
+    pub fn drain_audio(&mut self) -> Vec<f32> {
+        self.apu.drain_samples()
+    }
+
+    pub fn set_buttons(&mut self, port: Port, buttons: ButtonState) {
+        self.controllers[port as usize].set_buttons(buttons);
+    }
+
+    pub fn ppu(&self) -> &Ppu {
+        &self.ppu
+    }
+
+    pub fn cartridge(&self) -> &Cartridge {
+        &self.cartridge
+    }
+
+    pub fn cartridge_mut(&mut self) -> &mut Cartridge {
+        &mut self.cartridge
+    }
+
+    // records the accesses of the CPU and the DMA unit during each clock, off by default
+    pub(crate) fn set_bus_watch(&mut self, enabled: bool) {
+        self.bus_watch = enabled;
+        self.bus_accesses.clear();
+    }
+
+    // bus accesses of the last clock, empty unless bus watching is enabled
+    pub(crate) fn bus_accesses(&self) -> &[BusAccess] {
+        &self.bus_accesses
+    }
+
+    // moves the program counter between two instructions, returns false in the middle of an instruction
+    pub(crate) fn set_pc(&mut self, pc: u16) -> bool {
+        match self.cpu.get_cup_regs_mut() {
+            Some(regs) => {
+                regs.pc = pc;
+
+                // the CPU already requested the next opcode from the old PC
+                self.fetch = self.read_cpu(pc);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // reads the CPU bus without side effects, I/O registers read as 0xFF
+    pub(crate) fn peek_cpu(&self, addr: u16) -> u8 {
+        match addr {
+            addr if addr < 0x2000 => self.memory.cpu_read(addr % 0x800),
+            addr if addr < 0x4020 => 0xFF,
+            _ => self.cartridge.cpu_read(addr),
+        }
+    }
+
+    // reads RAM or PRG RAM without side effects, None for I/O registers and ROM
+    fn peek_memory(&self, addr: u16) -> Option<u8> {
+        match addr {
+            addr if addr < 0x2000 => Some(self.memory.cpu_read(addr % 0x800)),
+            addr if addr < 0x4020 => None,
+            _ => self.cartridge.peek_prg_ram(addr),
+        }
+    }
+
+    // writes RAM or PRG RAM without side effects, false for I/O registers and ROM
+    pub(crate) fn poke_cpu(&mut self, addr: u16, data: u8) -> bool {
+        match addr {
+            addr if addr < 0x2000 => {
+                self.memory.get_mem_mut()[(addr % 0x800) as usize] = data;
+                true
             }
+            addr if addr < 0x4020 => false,
+            _ => self.cartridge.poke_prg_ram(addr, data),
         }
     }
 
-    fn read_cpu(&self, addr: u16) -> Option<u8> {
+    // reads the PPU bus without side effects
+    pub(crate) fn peek_ppu(&self, addr: u16) -> u8 {
+        self.ppu.peek(&self.cartridge, addr)
+    }
+
+    pub(crate) fn poke_ppu(&mut self, addr: u16, data: u8) {
+        self.ppu.poke(&mut self.cartridge, addr, data);
+    }
+
+    fn read_cpu(&mut self, addr: u16) -> Option<u8> {
         match addr {
             // $0000-$1FFF RAM, 2KB mirrored 4 times
             addr if addr < 0x2000 => {
@@ -62,13 +289,20 @@ impl Emulator {
             }
             // $2000-$3FFF PPU registers, 8 byte mirrored several times
             addr if addr < 0x4000 => {
-                println!("PPU not implemented yet (read {:#04X})", addr);
-                Some(0)
+                Some(self.ppu.cpu_read(addr, &mut self.cartridge))
+            }
+            // $4015 APU status, bit 5 is open bus
+            0x4015 => {
+                Some(self.apu.read_status() | (self.open_bus & 0x20))
             }
-            // $4000-$4017 APU / IO
+            // $4016-$4017 controller ports, only the lowest bits are driven, the rest is open bus
+            0x4016 | 0x4017 => {
+                let port = (addr - 0x4016) as usize;
+                Some((self.open_bus & 0xE0) | self.controllers[port].read())
+            }
+            // $4000-$4014 APU registers are write-only
             addr if addr < 0x4018 => {
-                println!("APU and IO not implemented yet (read {:#04X})", addr);
-                Some(0)
+                Some(self.open_bus)
             }
             // $4018-$401F CPU Test Mode stuff
             addr if addr < 0x4020 => {
@@ -90,11 +324,21 @@ impl Emulator {
             }
             // $2000-$3FFF PPU registers, 8 byte mirrored several times
             addr if addr < 0x4000 => {
-                println!("PPU not implemented yet (write {:#04X}, {:#02X})", addr, data);
+                self.ppu.cpu_write(addr, data, &mut self.cartridge);
+            }
+            // $4014 OAM DMA
+            0x4014 => {
+                self.dma.start(data, self.cycles % 2 == 1);
+            }
+            // $4016 controller strobe, shared by both ports
+            0x4016 => {
+                for controller in self.controllers.iter_mut() {
+                    controller.write_strobe(data & 1 == 1);
+                }
             }
-            // $4000-$4017 APU / IO
+            // $4000-$4017 APU
             addr if addr < 0x4018 => {
-                println!("APU and IO not implemented yet (write {:#04X}, {:#02X})", addr, data);
+                self.apu.cpu_write(addr, data);
             }
             // $4018-$401F CPU Test Mode stuff
             addr if addr < 0x4020 => {
@@ -1,10 +1,18 @@
 extern crate clap;
-use clap::{Arg, App};
+use clap::{Arg, App, AppSettings, SubCommand};
+
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::process;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use nesferratu_core::Emulator;
-use nesferratu_core::cartridge::Cartridge;
+use nesferratu_core::cartridge::{Cartridge, ConsoleType, Header};
 use nesferratu_core::debugger;
 use nesferratu_core::debugger::Command;
+use nesferratu_core::debugger::trace::{self, TraceComparison};
+use nesferratu_core::test_rom;
 
 fn main() {
 
@@ -12,6 +20,7 @@ fn main() {
         .version("0.1-turboalpha")
         .author("SpitfireX")
         .about("Tries (and and by try i mean it really tries its best) to emulate a NES. Written in Rust btw 🦀")
+        .setting(AppSettings::SubcommandsNegateReqs)
         .arg(Arg::with_name("debugger")
             .short("d")
             .takes_value(false)
@@ -25,19 +34,57 @@ fn main() {
             .required(true)
             .index(1)
             .help("The ROM file to load"))
+        .subcommand(SubCommand::with_name("nestest")
+            .about("Runs nestest.nes from $C000 and compares its trace against a golden log in Nintendulator format")
+            .arg(Arg::with_name("ROM")
+                .required(true)
+                .index(1)
+                .help("The nestest ROM file"))
+            .arg(Arg::with_name("LOG")
+                .required(true)
+                .index(2)
+                .help("The golden log, e.g. nestest.log"))
+            .arg(Arg::with_name("context")
+                .long("context")
+                .takes_value(true)
+                .default_value("5")
+                .help("Number of matching lines shown before a divergence")))
+        .subcommand(SubCommand::with_name("test-rom")
+            .about("Runs test ROMs that report their result at $6000 without any output and prints a summary")
+            .arg(Arg::with_name("ROM")
+                .required(true)
+                .multiple(true)
+                .help("The test ROM files"))
+            .arg(Arg::with_name("frames")
+                .long("frames")
+                .takes_value(true)
+                .default_value("7200")
+                .help("Number of frames after which a test ROM times out")))
+        .subcommand(SubCommand::with_name("info")
+            .about("Prints the parsed header and checksums of a ROM without running it")
+            .arg(Arg::with_name("ROM")
+                .required(true)
+                .index(1)
+                .help("The ROM file")))
         .get_matches();
 
-    let cartridge;
+    if let Some(args) = cli_args.subcommand_matches("nestest") {
+        nestest(args);
+        return;
+    }
 
-    match Cartridge::read_from_file(cli_args.value_of("ROM").unwrap()) {
-        Ok(c) => {
-            cartridge = c;
-        }
-        Err(e) => {
-            panic!("Could not read ROM file: {}", e);
-        }
+    if let Some(args) = cli_args.subcommand_matches("info") {
+        info(args);
+        return;
+    }
+
+    if let Some(args) = cli_args.subcommand_matches("test-rom") {
+        test_roms(args);
+        return;
     }
 
+    let cartridge = read_cartridge(cli_args.value_of("ROM").unwrap());
+
     let mut emu = Emulator::new(cartridge);
     
     if cli_args.is_present("debugger") {
@@ -53,8 +100,189 @@ fn main() {
         debugger.run();
         
     } else {
-        loop {
-            emu.clock();
+        // stop on Ctrl-C so the emulator is dropped and battery saves are written
+        let running = Arc::new(AtomicBool::new(true));
+        let r = running.clone();
+        ctrlc::set_handler(move || {
+            r.store(false, Ordering::SeqCst);
+        }).expect("Error setting Ctrl-C handler");
+
+        while running.load(Ordering::SeqCst) {
+            // a jammed CPU is reported once, the rest of the system keeps running like on the real console
+            if let Err(e) = emu.run_frame() {
+                eprintln!("{}", e);
+            }
+        }
+    }
+}
+fn read_cartridge(path: &str) -> Cartridge {
+    match Cartridge::read_from_file(path) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Could not read ROM file {}: {}", path, e);
+            process::exit(2);
+        }
+    }
+}
+
+fn nestest(args: &clap::ArgMatches) {
+    let mut emu = Emulator::new(read_cartridge(args.value_of("ROM").unwrap()));
+
+    let log = match File::open(args.value_of("LOG").unwrap()) {
+        Ok(f) => BufReader::new(f),
+        Err(e) => {
+            eprintln!("Could not read golden log: {}", e);
+            process::exit(2);
+        }
+    };
+
+    let context = match args.value_of("context").unwrap().parse() {
+        Ok(n) => n,
+        Err(_) => {
+            eprintln!("Invalid number of context lines");
+            process::exit(2);
+        }
+    };
+
+    match trace::compare_log(&mut emu, trace::NESTEST_START, log, context) {
+        Ok(TraceComparison::Match(lines)) => {
+            println!("Trace matches all {} lines of the golden log", lines);
+        }
+        Ok(TraceComparison::Diverged(divergence)) => {
+            println!("{}", divergence);
+            process::exit(1);
         }
+        Err(e) => {
+            eprintln!("Could not read golden log: {}", e);
+            process::exit(2);
+        }
+    }
+}
+
+fn test_roms(args: &clap::ArgMatches) {
+    let max_frames = match args.value_of("frames").unwrap().parse() {
+        Ok(n) => n,
+        Err(_) => {
+            eprintln!("Invalid number of frames");
+            process::exit(2);
+        }
+    };
+
+    let mut results = Vec::new();
+
+    for path in args.values_of("ROM").unwrap() {
+        let mut cartridge = match Cartridge::read_from_file(path) {
+            Ok(c) => c,
+            Err(e) => {
+                eprintln!("Could not read ROM file {}: {}", path, e);
+                results.push((path, None));
+                continue;
+            }
+        };
+
+        // test runs should not leave save files behind
+        cartridge.set_save_path::<&str>(None);
+
+        let mut emu = Emulator::new(cartridge);
+        let result = test_rom::run_test_rom(&mut emu, max_frames);
+
+        println!("{}: {} after {} frames", path, result.status, result.frames);
+        for line in result.message.lines() {
+            println!("    {}", line);
+        }
+
+        results.push((path, Some(result)));
+    }
+
+    let passed = results.iter()
+        .filter(|(_, r)| r.as_ref().is_some_and(|r| r.passed()))
+        .count();
+
+    println!();
+    println!("{:<12} ROM", "Result");
+    for (path, result) in results.iter() {
+        let status = match result {
+            Some(r) => r.status.to_string(),
+            None => String::from("unreadable"),
+        };
+        println!("{:<12} {}", status, path);
+    }
+    println!("{} of {} test ROMs passed", passed, results.len());
+
+    if passed != results.len() {
+        process::exit(1);
+    }
+}
+
+fn info(args: &clap::ArgMatches) {
+    let path = args.value_of("ROM").unwrap();
+
+    // read the file directly, loading it as a cartridge would also pick up its save file
+    let image = match fs::read(path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Could not read ROM file {}: {}", path, e);
+            process::exit(2);
+        }
+    };
+
+    // only the header is parsed so ROMs with unsupported mappers can be inspected too
+    let header = match Header::parse(&image) {
+        Ok(h) => h,
+        Err(e) => {
+            eprintln!("Could not read ROM file {}: {}", path, e);
+            process::exit(2);
+        }
+    };
+
+    // sections are cut short if the file is truncated
+    let section = |start: usize, len: usize| &image[start.min(image.len())..start.saturating_add(len).min(image.len())];
+    let prg_start = 16 + if header.has_trainer() { 512 } else { 0 };
+    let prg_rom = section(prg_start, header.prg_rom_size());
+    let chr_rom = section(prg_start.saturating_add(header.prg_rom_size()), header.chr_rom_size());
+
+    let size = |bytes: usize| {
+        if bytes >= 1024 && bytes.is_multiple_of(1024) {
+            format!("{} KB", bytes / 1024)
+        } else {
+            format!("{} bytes", bytes)
+        }
+    };
+
+    let console = match header.console_type() {
+        ConsoleType::Nes => String::from("NES/Famicom"),
+        ConsoleType::VsSystem { ppu, hardware } => format!("Vs. System (PPU type {}, hardware type {})", ppu, hardware),
+        ConsoleType::Playchoice10 => String::from("PlayChoice-10"),
+        ConsoleType::Extended(t) => format!("Extended console type {}", t),
+    };
+
+    println!("File:             {}", path);
+    println!("Format:           {}", if header.is_nes20() { "NES 2.0" } else { "iNES" });
+    println!("Mapper:           {} (submapper {})", header.mapper_id(), header.submapper());
+    println!("PRG ROM:          {}", size(header.prg_rom_size()));
+    println!("CHR ROM:          {}", size(header.chr_rom_size()));
+    println!("PRG RAM:          {}", size(header.prg_ram_size()));
+    println!("PRG NVRAM:        {}", size(header.prg_nvram_size()));
+    println!("CHR RAM:          {}", size(header.chr_ram_size()));
+    println!("CHR NVRAM:        {}", size(header.chr_nvram_size()));
+    println!("Mirroring:        {:?}", header.mirroring());
+    println!("Battery:          {}", if header.has_battery() { "yes" } else { "no" });
+    println!("Trainer:          {}", if header.has_trainer() { "yes" } else { "no" });
+    println!("Region:           {:?}", header.timing());
+    println!("Console:          {}", console);
+
+    if header.is_nes20() {
+        println!("Misc ROMs:        {}", header.misc_rom_count());
+        println!("Expansion device: {}", header.default_expansion_device());
+    }
+
+    if prg_rom.len() < header.prg_rom_size() || chr_rom.len() < header.chr_rom_size() {
+        println!("Warning:          file is truncated, the checksums only cover what is there");
+    }
+
+    println!();
+    println!("{:<8} {:<8} SHA-1", "", "CRC32");
+    for (name, data) in [("PRG ROM", prg_rom), ("CHR ROM", chr_rom), ("File", &image[..])] {
+        println!("{:<8} {:08X} {}", name, crc32fast::hash(data), sha1_smol::Sha1::from(data).digest());
     }
-}
\ No newline at end of file
+}
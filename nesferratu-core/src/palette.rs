@@ -0,0 +1,111 @@
+use std::{io::{Result as IoResult, Read, Error, ErrorKind}, path::Path};
+use std::fs::File;
+
+// the 2C02 palette as commonly used on the nesdev wiki
+static NTSC_COLORS: [[u8; 3]; 64] = [
+    [ 84,  84,  84], [  0,  30, 116], [  8,  16, 144], [ 48,   0, 136], [ 68,   0, 100], [ 92,   0,  48], [ 84,   4,   0], [ 60,  24,   0],
+    [ 32,  42,   0], [  8,  58,   0], [  0,  64,   0], [  0,  60,   0], [  0,  50,  60], [  0,   0,   0], [  0,   0,   0], [  0,   0,   0],
+    [152, 150, 152], [  8,  76, 196], [ 48,  50, 236], [ 92,  30, 228], [136,  20, 176], [160,  20, 100], [152,  34,  32], [120,  60,   0],
+    [ 84,  90,   0], [ 40, 114,   0], [  8, 124,   0], [  0, 118,  40], [  0, 102, 120], [  0,   0,   0], [  0,   0,   0], [  0,   0,   0],
+    [236, 238, 236], [ 76, 154, 236], [120, 124, 236], [176,  98, 236], [228,  84, 236], [236,  88, 180], [236, 106, 100], [212, 136,  32],
+    [160, 170,   0], [116, 196,   0], [ 76, 208,  32], [ 56, 204, 108], [ 56, 180, 204], [ 60,  60,  60], [  0,   0,   0], [  0,   0,   0],
+    [236, 238, 236], [168, 204, 236], [188, 188, 236], [212, 178, 236], [236, 174, 236], [236, 174, 212], [236, 180, 176], [228, 196, 144],
+    [204, 210, 120], [180, 222, 120], [168, 226, 144], [152, 226, 180], [160, 214, 228], [160, 162, 160], [  0,   0,   0], [  0,   0,   0],
+];
+
+// FCEUX' default palette
+static FCEUX_COLORS: [[u8; 3]; 64] = [
+    [116, 116, 116], [ 36,  24, 140], [  0,   0, 168], [ 68,   0, 156], [140,   0, 116], [168,   0,  16], [164,   0,   0], [124,   8,   0],
+    [ 64,  44,   0], [  0,  68,   0], [  0,  80,   0], [  0,  60,  20], [ 24,  60,  92], [  0,   0,   0], [  0,   0,   0], [  0,   0,   0],
+    [188, 188, 188], [  0, 112, 236], [ 32,  56, 236], [128,   0, 240], [188,   0, 188], [228,   0,  88], [216,  40,   0], [200,  76,  12],
+    [136, 112,   0], [  0, 148,   0], [  0, 168,   0], [  0, 144,  56], [  0, 128, 136], [  0,   0,   0], [  0,   0,   0], [  0,   0,   0],
+    [252, 252, 252], [ 60, 188, 252], [ 92, 148, 252], [204, 136, 252], [244, 120, 252], [252, 116, 180], [252, 116,  96], [252, 152,  56],
+    [240, 188,  60], [128, 208,  16], [ 76, 220,  72], [ 88, 248, 152], [  0, 232, 216], [120, 120, 120], [  0,   0,   0], [  0,   0,   0],
+    [252, 252, 252], [168, 228, 252], [196, 212, 252], [212, 200, 252], [252, 196, 252], [252, 196, 216], [252, 188, 176], [252, 216, 168],
+    [252, 228, 160], [224, 252, 160], [168, 240, 188], [176, 252, 204], [156, 252, 240], [196, 196, 196], [  0,   0,   0], [  0,   0,   0],
+];
+
+// emphasized channels keep their intensity, the others get dimmed by roughly this factor
+const EMPHASIS_ATTENUATION: f32 = 0.816;
+
+pub struct Palette {
+    // 64 base colors, followed by 7 emphasized variants of them if the palette provides them
+    colors: Box<[[u8; 3]]>,
+}
+
+impl Palette {
+
+    pub fn ntsc() -> Palette {
+        Palette {
+            colors: Box::new(NTSC_COLORS),
+        }
+    }
+
+    pub fn fceux() -> Palette {
+        Palette {
+            colors: Box::new(FCEUX_COLORS),
+        }
+    }
+
+    pub fn read_from_file<P: AsRef<Path>>(path: P) -> IoResult<Palette> {
+        let file = File::open(path)?;
+        Self::read(file)
+    }
+
+    // reads a .pal file, either 64 or 512 (with emphasis) RGB triplets
+    pub fn read<R: Read>(mut reader: R) -> IoResult<Palette> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        if data.len() != 64 * 3 && data.len() != 512 * 3 {
+            return Err(Error::new(ErrorKind::InvalidData, "Palette data must contain either 64 or 512 colors"));
+        }
+
+        let colors: Vec<[u8; 3]> = data.chunks_exact(3)
+            .map(|c| [c[0], c[1], c[2]])
+            .collect();
+
+        Ok(
+            Palette {
+                colors: colors.into_boxed_slice(),
+            }
+        )
+    }
+
+    // converts an indexed pixel from the PPU frame buffer to RGB
+    pub fn rgb(&self, pixel: u16) -> [u8; 3] {
+        let index = (pixel & 0x3F) as usize;
+        let emphasis = ((pixel >> 6) & 0x7) as usize;
+
+        if self.colors.len() == 512 {
+            return self.colors[emphasis * 64 + index];
+        }
+
+        let mut color = self.colors[index];
+
+        // the $xE and $xF columns are black and stay unaffected
+        if emphasis != 0 && index & 0x0E != 0x0E {
+            // bit 0 emphasizes red, bit 1 green and bit 2 blue
+            for (channel, value) in color.iter_mut().enumerate() {
+                if emphasis & (1 << channel) == 0 {
+                    *value = (*value as f32 * EMPHASIS_ATTENUATION) as u8;
+                }
+            }
+        }
+
+        color
+    }
+
+    pub fn to_rgba(&self, frame: &[u16], out: &mut [u8]) {
+        for (pixel, rgba) in frame.iter().zip(out.chunks_exact_mut(4)) {
+            let [r, g, b] = self.rgb(*pixel);
+            rgba.copy_from_slice(&[r, g, b, 0xFF]);
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::ntsc()
+    }
+}
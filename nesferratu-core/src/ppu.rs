@@ -0,0 +1,720 @@
+use crate::PpuBusDevice;
+use crate::cartridge::{Cartridge, Mirroring};
+
+pub const SCREEN_WIDTH: usize = 256;
+pub const SCREEN_HEIGHT: usize = 240;
+
+#[repr(u8)]
+enum CtrlFlags {
+    IncrementMode = (1 << 2),   // VRAM address increment per PPUDATA access, 0: 1, 1: 32
+    SpriteTable = (1 << 3),     // sprite pattern table for 8x8 sprites, 0: $0000, 1: $1000
+    BackgroundTable = (1 << 4), // background pattern table, 0: $0000, 1: $1000
+    SpriteSize = (1 << 5),      // 0: 8x8, 1: 8x16
+    NmiEnable = (1 << 7),       // generate NMI at the start of vblank
+}
+
+#[repr(u8)]
+enum MaskFlags {
+    Greyscale = (1 << 0),
+    BackgroundLeft = (1 << 1),  // show background in leftmost 8 pixels
+    SpritesLeft = (1 << 2),     // show sprites in leftmost 8 pixels
+    Background = (1 << 3),      // show background
+    Sprites = (1 << 4),         // show sprites
+}
+
+#[repr(u8)]
+enum StatusFlags {
+    SpriteOverflow = (1 << 5),
+    SpriteZeroHit = (1 << 6),
+    VBlank = (1 << 7),
+}
+
+pub struct Ppu {
+    // registers
+    ctrl: u8,
+    mask: u8,
+    status: u8,
+    oam_addr: u8,
+
+    // internal registers, see https://wiki.nesdev.org/w/index.php/PPU_scrolling
+    v: u16,             // current VRAM address
+    t: u16,             // temporary VRAM address
+    x: u8,              // fine x scroll
+    w: bool,            // first/second write toggle
+    read_buffer: u8,    // PPUDATA read buffer
+    io_latch: u8,       // the data bus between CPU and PPU, returned for write-only registers
+
+    // memory
+    oam: [u8; 256],
+    nametables: [u8; 4096], // 2KB CIRAM, the upper half is only used by four screen cartridges
+    palette: [u8; 32],
+
+    // timing
+    scanline: u16,
+    dot: u16,
+    frame: u64,
+    odd_frame: bool,
+    nmi_line: bool,
+    nmi_pending: bool,
+    frame_ready: bool,
+
+    // background pipeline
+    next_tile_id: u8,
+    next_tile_attrib: u8,
+    next_tile_lo: u8,
+    next_tile_hi: u8,
+    shifter_pattern_lo: u16,
+    shifter_pattern_hi: u16,
+    shifter_attrib_lo: u16,
+    shifter_attrib_hi: u16,
+
+    // sprites for the current scanline
+    secondary_oam: [u8; 32],
+    sprite_count: usize,
+    sprite_zero_next: bool,
+    sprite_zero_line: bool,
+    sprite_patterns_lo: [u8; 8],
+    sprite_patterns_hi: [u8; 8],
+    sprite_attribs: [u8; 8],
+    sprite_xs: [u8; 8],
+
+    frame_buffer: Box<[u16]>,
+}
+
+impl Ppu {
+    pub fn new() -> Ppu {
+        Ppu {
+            ctrl: 0,
+            mask: 0,
+            status: 0,
+            oam_addr: 0,
+
+            v: 0,
+            t: 0,
+            x: 0,
+            w: false,
+            read_buffer: 0,
+            io_latch: 0,
+
+            oam: [0x00; 256],
+            nametables: [0x00; 4096],
+            palette: [0x00; 32],
+
+            scanline: 0,
+            dot: 0,
+            frame: 0,
+            odd_frame: false,
+            nmi_line: false,
+            nmi_pending: false,
+            frame_ready: false,
+
+            next_tile_id: 0,
+            next_tile_attrib: 0,
+            next_tile_lo: 0,
+            next_tile_hi: 0,
+            shifter_pattern_lo: 0,
+            shifter_pattern_hi: 0,
+            shifter_attrib_lo: 0,
+            shifter_attrib_hi: 0,
+
+            secondary_oam: [0xFF; 32],
+            sprite_count: 0,
+            sprite_zero_next: false,
+            sprite_zero_line: false,
+            sprite_patterns_lo: [0x00; 8],
+            sprite_patterns_hi: [0x00; 8],
+            sprite_attribs: [0x00; 8],
+            sprite_xs: [0x00; 8],
+
+            frame_buffer: vec![0x00; SCREEN_WIDTH * SCREEN_HEIGHT].into_boxed_slice(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.ctrl = 0;
+        self.mask = 0;
+        self.w = false;
+        self.x = 0;
+        self.t = 0;
+        self.read_buffer = 0;
+        self.scanline = 0;
+        self.dot = 0;
+        self.frame = 0;
+        self.odd_frame = false;
+        self.nmi_line = false;
+        self.nmi_pending = false;
+        self.frame_ready = false;
+    }
+
+    pub fn scanline(&self) -> u16 {
+        self.scanline
+    }
+
+    pub fn dot(&self) -> u16 {
+        self.dot
+    }
+
+    pub fn frame_count(&self) -> u64 {
+        self.frame
+    }
+
+    // every pixel holds a palette index in bits 0-5 and the PPUMASK color emphasis bits in bits 6-8
+    pub fn frame_buffer(&self) -> &[u16] {
+        &self.frame_buffer
+    }
+
+    // returns true once for every rising edge of the PPU's NMI output
+    pub fn poll_nmi(&mut self) -> bool {
+        let nmi = self.nmi_pending;
+        self.nmi_pending = false;
+        nmi
+    }
+
+    // returns true once for every frame that has been completely drawn
+    pub fn poll_frame(&mut self) -> bool {
+        let ready = self.frame_ready;
+        self.frame_ready = false;
+        ready
+    }
+
+    pub(crate) fn cpu_read(&mut self, addr: u16, cart: &mut Cartridge) -> u8 {
+        // registers are mirrored every 8 bytes from $2000 to $3FFF
+        match addr & 0x7 {
+            // $2002 PPUSTATUS, the lower 5 bits are open bus
+            2 => {
+                let data = (self.status & 0xE0) | (self.io_latch & 0x1F);
+                self.set_status(StatusFlags::VBlank, false);
+                self.update_nmi();
+                self.w = false;
+                self.io_latch = data;
+            }
+            // $2004 OAMDATA
+            4 => {
+                let mut data = self.oam[self.oam_addr as usize];
+                // bits 2-4 of the sprite attribute byte don't exist
+                if self.oam_addr & 0x3 == 2 {
+                    data &= 0xE3;
+                }
+                self.io_latch = data;
+            }
+            // $2007 PPUDATA
+            7 => {
+                let addr = self.v & 0x3FFF;
+                if addr < 0x3F00 {
+                    // reads outside palette RAM are delayed by one read
+                    self.io_latch = self.read_buffer;
+                    self.read_buffer = self.bus_read(cart, addr);
+                } else {
+                    // palette reads are immediate but the buffer gets filled with the nametable byte "below"
+                    self.io_latch = (self.bus_read(cart, addr) & 0x3F) | (self.io_latch & 0xC0);
+                    self.read_buffer = self.bus_read(cart, addr - 0x1000);
+                }
+                self.increment_v();
+            }
+            // write-only registers return the contents of the latch
+            _ => {}
+        }
+
+        self.io_latch
+    }
+
+    pub(crate) fn cpu_write(&mut self, addr: u16, data: u8, cart: &mut Cartridge) {
+        self.io_latch = data;
+
+        match addr & 0x7 {
+            // $2000 PPUCTRL
+            0 => {
+                self.ctrl = data;
+                // nametable select goes into bits 10-11 of t
+                self.t = (self.t & 0xF3FF) | ((data as u16 & 0x3) << 10);
+                self.update_nmi();
+            }
+            // $2001 PPUMASK
+            1 => {
+                self.mask = data;
+            }
+            // $2002 PPUSTATUS is read-only
+            2 => {}
+            // $2003 OAMADDR
+            3 => {
+                self.oam_addr = data;
+            }
+            // $2004 OAMDATA
+            4 => {
+                self.oam[self.oam_addr as usize] = data;
+                self.oam_addr = self.oam_addr.wrapping_add(1);
+            }
+            // $2005 PPUSCROLL
+            5 => {
+                if !self.w {
+                    self.t = (self.t & !0x001F) | (data as u16 >> 3);
+                    self.x = data & 0x7;
+                } else {
+                    self.t = (self.t & 0x8C1F) | ((data as u16 & 0x7) << 12) | ((data as u16 & 0xF8) << 2);
+                }
+                self.w = !self.w;
+            }
+            // $2006 PPUADDR
+            6 => {
+                if !self.w {
+                    self.t = (self.t & 0x80FF) | ((data as u16 & 0x3F) << 8);
+                } else {
+                    self.t = (self.t & 0xFF00) | data as u16;
+                    self.v = self.t;
+                }
+                self.w = !self.w;
+            }
+            // $2007 PPUDATA
+            7 => {
+                self.bus_write(cart, self.v & 0x3FFF, data);
+                self.increment_v();
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    pub(crate) fn clock(&mut self, cart: &mut Cartridge) {
+        let visible_line = self.scanline < 240;
+        let prerender_line = self.scanline == 261;
+
+        if self.rendering_enabled() && (visible_line || prerender_line) {
+            self.render_cycle(cart, prerender_line);
+        }
+
+        if visible_line && self.dot >= 1 && self.dot <= 256 {
+            self.draw_pixel();
+        }
+
+        if self.scanline == 241 && self.dot == 1 {
+            self.frame_ready = true;
+            self.set_status(StatusFlags::VBlank, true);
+            self.update_nmi();
+        }
+
+        if prerender_line && self.dot == 1 {
+            self.set_status(StatusFlags::VBlank, false);
+            self.set_status(StatusFlags::SpriteZeroHit, false);
+            self.set_status(StatusFlags::SpriteOverflow, false);
+            self.update_nmi();
+        }
+
+        // advance to the next dot, odd frames skip the last dot of the pre-render line while rendering
+        if prerender_line && self.dot == 339 && self.odd_frame && self.rendering_enabled() {
+            self.dot = 340;
+        }
+
+        self.dot += 1;
+        if self.dot > 340 {
+            self.dot = 0;
+            self.scanline += 1;
+
+            if self.scanline > 261 {
+                self.scanline = 0;
+                self.frame += 1;
+                self.odd_frame = !self.odd_frame;
+            }
+        }
+    }
+
+    fn render_cycle(&mut self, cart: &mut Cartridge, prerender_line: bool) {
+        let dot = self.dot;
+
+        // background fetches, see https://wiki.nesdev.org/w/index.php/PPU_rendering
+        if (2..=257).contains(&dot) || (322..=337).contains(&dot) {
+            self.shift_background();
+
+            match (dot - 1) % 8 {
+                0 => {
+                    self.load_background_shifters();
+                    self.next_tile_id = self.bus_read(cart, 0x2000 | (self.v & 0x0FFF));
+                }
+                2 => {
+                    let addr = 0x23C0 | (self.v & 0x0C00) | ((self.v >> 4) & 0x38) | ((self.v >> 2) & 0x07);
+                    let mut attrib = self.bus_read(cart, addr);
+                    // select the 2x2 tile quadrant of the attribute byte
+                    if self.v & 0x40 != 0 {
+                        attrib >>= 4;
+                    }
+                    if self.v & 0x02 != 0 {
+                        attrib >>= 2;
+                    }
+                    self.next_tile_attrib = attrib & 0x3;
+                }
+                4 => {
+                    let addr = self.background_pattern_addr();
+                    self.next_tile_lo = self.bus_read(cart, addr);
+                }
+                6 => {
+                    let addr = self.background_pattern_addr() + 8;
+                    self.next_tile_hi = self.bus_read(cart, addr);
+                }
+                7 => {
+                    self.increment_x();
+                }
+                _ => {}
+            }
+        }
+
+        if dot == 256 {
+            self.increment_y();
+        }
+
+        if dot == 257 {
+            self.load_background_shifters();
+            // copy horizontal position from t to v
+            self.v = (self.v & !0x041F) | (self.t & 0x041F);
+
+            if prerender_line {
+                self.sprite_count = 0;
+                self.sprite_zero_next = false;
+                self.secondary_oam = [0xFF; 32];
+            } else {
+                self.evaluate_sprites();
+            }
+        }
+
+        // sprite pattern fetches for the next scanline
+        if (257..=320).contains(&dot) {
+            if dot == 257 {
+                self.sprite_zero_line = self.sprite_zero_next;
+            }
+
+            let slot = ((dot - 257) / 8) as usize;
+            match (dot - 257) % 8 {
+                4 => {
+                    let addr = self.sprite_pattern_addr(slot);
+                    let data = self.bus_read(cart, addr);
+                    self.sprite_patterns_lo[slot] = self.flip_sprite(slot, data);
+                }
+                6 => {
+                    let addr = self.sprite_pattern_addr(slot) + 8;
+                    let data = self.bus_read(cart, addr);
+                    self.sprite_patterns_hi[slot] = self.flip_sprite(slot, data);
+                }
+                _ => {}
+            }
+        }
+
+        // copy vertical position from t to v
+        if prerender_line && (280..=304).contains(&dot) {
+            self.v = (self.v & !0x7BE0) | (self.t & 0x7BE0);
+        }
+
+        // unused nametable fetches at the end of the line
+        if dot == 338 || dot == 340 {
+            self.next_tile_id = self.bus_read(cart, 0x2000 | (self.v & 0x0FFF));
+        }
+    }
+
+    fn evaluate_sprites(&mut self) {
+        let height = self.sprite_height();
+
+        self.secondary_oam = [0xFF; 32];
+        self.sprite_count = 0;
+        self.sprite_zero_next = false;
+
+        for i in 0..64 {
+            let y = self.oam[i * 4] as u16;
+            let row = self.scanline.wrapping_sub(y);
+
+            if row < height {
+                if self.sprite_count == 8 {
+                    self.set_status(StatusFlags::SpriteOverflow, true);
+                    break;
+                }
+
+                if i == 0 {
+                    self.sprite_zero_next = true;
+                }
+
+                let slot = self.sprite_count * 4;
+                self.secondary_oam[slot..slot + 4].copy_from_slice(&self.oam[i * 4..i * 4 + 4]);
+                self.sprite_count += 1;
+            }
+        }
+
+        for slot in 0..8 {
+            self.sprite_attribs[slot] = self.secondary_oam[slot * 4 + 2];
+            self.sprite_xs[slot] = self.secondary_oam[slot * 4 + 3];
+        }
+    }
+
+    fn sprite_pattern_addr(&self, slot: usize) -> u16 {
+        let y = self.secondary_oam[slot * 4] as u16;
+        let tile = self.secondary_oam[slot * 4 + 1] as u16;
+        let attrib = self.secondary_oam[slot * 4 + 2];
+
+        // empty slots still fetch tile $FF
+        let mut row = if slot < self.sprite_count {
+            self.scanline.wrapping_sub(y) & 0xF
+        } else {
+            0
+        };
+
+        if self.sprite_height() == 16 {
+            if attrib & 0x80 != 0 {
+                row = 15 - row;
+            }
+            let table = (tile & 1) << 12;
+            let tile = (tile & 0xFE) + if row >= 8 { 1 } else { 0 };
+            table | (tile << 4) | (row & 0x7)
+        } else {
+            if attrib & 0x80 != 0 {
+                row = 7 - (row & 0x7);
+            }
+            let table = if self.get_ctrl(CtrlFlags::SpriteTable) { 0x1000 } else { 0x0000 };
+            table | (tile << 4) | (row & 0x7)
+        }
+    }
+
+    fn flip_sprite(&self, slot: usize, data: u8) -> u8 {
+        if slot >= self.sprite_count {
+            // transparent
+            0x00
+        } else if self.sprite_attribs[slot] & 0x40 != 0 {
+            data.reverse_bits()
+        } else {
+            data
+        }
+    }
+
+    fn draw_pixel(&mut self) {
+        let x = (self.dot - 1) as usize;
+        let y = self.scanline as usize;
+
+        // background pixel
+        let mut bg_pixel = 0u8;
+        let mut bg_palette = 0u8;
+
+        if self.get_mask(MaskFlags::Background) && (x >= 8 || self.get_mask(MaskFlags::BackgroundLeft)) {
+            let bit = 0x8000 >> self.x;
+            bg_pixel = ((self.shifter_pattern_lo & bit != 0) as u8) | (((self.shifter_pattern_hi & bit != 0) as u8) << 1);
+            bg_palette = ((self.shifter_attrib_lo & bit != 0) as u8) | (((self.shifter_attrib_hi & bit != 0) as u8) << 1);
+        }
+
+        // sprite pixel, the first opaque sprite in OAM order wins
+        let mut sp_pixel = 0u8;
+        let mut sp_palette = 0u8;
+        let mut sp_behind = false;
+        let mut sp_zero = false;
+
+        if self.get_mask(MaskFlags::Sprites) && (x >= 8 || self.get_mask(MaskFlags::SpritesLeft)) {
+            for slot in 0..self.sprite_count {
+                let offset = x.wrapping_sub(self.sprite_xs[slot] as usize);
+                if offset < 8 {
+                    let bit = 0x80 >> offset;
+                    let pixel = ((self.sprite_patterns_lo[slot] & bit != 0) as u8) | (((self.sprite_patterns_hi[slot] & bit != 0) as u8) << 1);
+
+                    if pixel != 0 {
+                        sp_pixel = pixel;
+                        sp_palette = (self.sprite_attribs[slot] & 0x3) + 4;
+                        sp_behind = self.sprite_attribs[slot] & 0x20 != 0;
+                        sp_zero = slot == 0 && self.sprite_zero_line;
+                        break;
+                    }
+                }
+            }
+        }
+
+        // sprite 0 hit never happens at x=255
+        if sp_zero && bg_pixel != 0 && sp_pixel != 0 && x != 255 {
+            self.set_status(StatusFlags::SpriteZeroHit, true);
+        }
+
+        let (pixel, palette) = match (bg_pixel, sp_pixel) {
+            (0, 0) => (0, 0),
+            (0, _) => (sp_pixel, sp_palette),
+            (_, 0) => (bg_pixel, bg_palette),
+            _ if sp_behind => (bg_pixel, bg_palette),
+            _ => (sp_pixel, sp_palette),
+        };
+
+        let mut color = self.palette[palette_index(((palette as u16) << 2) | pixel as u16)];
+        if self.get_mask(MaskFlags::Greyscale) {
+            color &= 0x30;
+        }
+
+        let emphasis = (self.mask as u16 & 0xE0) << 1;
+        self.frame_buffer[y * SCREEN_WIDTH + x] = (color as u16 & 0x3F) | emphasis;
+    }
+
+    fn shift_background(&mut self) {
+        self.shifter_pattern_lo <<= 1;
+        self.shifter_pattern_hi <<= 1;
+        self.shifter_attrib_lo <<= 1;
+        self.shifter_attrib_hi <<= 1;
+    }
+
+    fn load_background_shifters(&mut self) {
+        self.shifter_pattern_lo = (self.shifter_pattern_lo & 0xFF00) | self.next_tile_lo as u16;
+        self.shifter_pattern_hi = (self.shifter_pattern_hi & 0xFF00) | self.next_tile_hi as u16;
+
+        // the attribute bits are the same for all 8 pixels of a tile
+        let lo = if self.next_tile_attrib & 1 != 0 { 0xFF } else { 0x00 };
+        let hi = if self.next_tile_attrib & 2 != 0 { 0xFF } else { 0x00 };
+        self.shifter_attrib_lo = (self.shifter_attrib_lo & 0xFF00) | lo;
+        self.shifter_attrib_hi = (self.shifter_attrib_hi & 0xFF00) | hi;
+    }
+
+    fn background_pattern_addr(&self) -> u16 {
+        let table = if self.get_ctrl(CtrlFlags::BackgroundTable) { 0x1000 } else { 0x0000 };
+        let fine_y = (self.v >> 12) & 0x7;
+        table | ((self.next_tile_id as u16) << 4) | fine_y
+    }
+
+    fn increment_x(&mut self) {
+        if self.v & 0x001F == 31 {
+            // wrap around and switch horizontal nametable
+            self.v &= !0x001F;
+            self.v ^= 0x0400;
+        } else {
+            self.v += 1;
+        }
+    }
+
+    fn increment_y(&mut self) {
+        if self.v & 0x7000 != 0x7000 {
+            // increment fine y
+            self.v += 0x1000;
+        } else {
+            self.v &= !0x7000;
+            let mut coarse_y = (self.v & 0x03E0) >> 5;
+
+            if coarse_y == 29 {
+                // wrap around and switch vertical nametable
+                coarse_y = 0;
+                self.v ^= 0x0800;
+            } else if coarse_y == 31 {
+                // coarse y can be set out of bounds, wraps around without switching nametables
+                coarse_y = 0;
+            } else {
+                coarse_y += 1;
+            }
+
+            self.v = (self.v & !0x03E0) | (coarse_y << 5);
+        }
+    }
+
+    fn increment_v(&mut self) {
+        if self.get_ctrl(CtrlFlags::IncrementMode) {
+            self.v = self.v.wrapping_add(32) & 0x7FFF;
+        } else {
+            self.v = self.v.wrapping_add(1) & 0x7FFF;
+        }
+    }
+
+    fn bus_read(&mut self, cart: &mut Cartridge, addr: u16) -> u8 {
+        cart.ppu_address(addr & 0x3FFF);
+
+        match addr & 0x3FFF {
+            // $0000-$1FFF pattern tables on the cartridge
+            addr if addr < 0x2000 => cart.ppu_read(addr),
+            // $2000-$3EFF nametables, mirrored according to the cartridge wiring
+            addr if addr < 0x3F00 => self.nametables[nametable_index(cart.mirroring(), addr)],
+            // $3F00-$3FFF palette RAM
+            addr => self.palette[palette_index(addr)],
+        }
+    }
+
+    fn bus_write(&mut self, cart: &mut Cartridge, addr: u16, data: u8) {
+        cart.ppu_address(addr & 0x3FFF);
+
+        match addr & 0x3FFF {
+            addr if addr < 0x2000 => cart.ppu_write(addr, data),
+            addr if addr < 0x3F00 => self.nametables[nametable_index(cart.mirroring(), addr)] = data,
+            addr => self.palette[palette_index(addr)] = data,
+        }
+    }
+
+    // reads the PPU bus without letting the mapper see the address
+    pub(crate) fn peek(&self, cart: &Cartridge, addr: u16) -> u8 {
+        match addr & 0x3FFF {
+            addr if addr < 0x2000 => cart.ppu_read(addr),
+            addr if addr < 0x3F00 => self.nametables[nametable_index(cart.mirroring(), addr)],
+            addr => self.palette[palette_index(addr)],
+        }
+    }
+
+    // writes the PPU bus without letting the mapper see the address, CHR ROM stays unchanged
+    pub(crate) fn poke(&mut self, cart: &mut Cartridge, addr: u16, data: u8) {
+        match addr & 0x3FFF {
+            addr if addr < 0x2000 => cart.ppu_write(addr, data),
+            addr if addr < 0x3F00 => self.nametables[nametable_index(cart.mirroring(), addr)] = data,
+            addr => self.palette[palette_index(addr)] = data,
+        }
+    }
+
+    pub(crate) fn oam(&self) -> &[u8] {
+        &self.oam
+    }
+
+    pub(crate) fn oam_mut(&mut self) -> &mut [u8] {
+        &mut self.oam
+    }
+
+    fn update_nmi(&mut self) {
+        let line = self.get_ctrl(CtrlFlags::NmiEnable) && self.status & (StatusFlags::VBlank as u8) != 0;
+
+        if line && !self.nmi_line {
+            self.nmi_pending = true;
+        }
+
+        self.nmi_line = line;
+    }
+
+    fn rendering_enabled(&self) -> bool {
+        self.get_mask(MaskFlags::Background) || self.get_mask(MaskFlags::Sprites)
+    }
+
+    fn sprite_height(&self) -> u16 {
+        if self.get_ctrl(CtrlFlags::SpriteSize) { 16 } else { 8 }
+    }
+
+    fn get_ctrl(&self, flag: CtrlFlags) -> bool {
+        self.ctrl & (flag as u8) != 0
+    }
+
+    fn get_mask(&self, flag: MaskFlags) -> bool {
+        self.mask & (flag as u8) != 0
+    }
+
+    fn set_status(&mut self, flag: StatusFlags, value: bool) {
+        if value {
+            self.status |= flag as u8;
+        } else {
+            self.status &= !(flag as u8);
+        }
+    }
+}
+
+impl Default for Ppu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn nametable_index(mirroring: Mirroring, addr: u16) -> usize {
+    let addr = (addr & 0x0FFF) as usize;
+    let table = addr / 0x400;
+    let offset = addr % 0x400;
+
+    let physical = match mirroring {
+        Mirroring::Horizontal => table / 2,
+        Mirroring::Vertical => table % 2,
+        Mirroring::SingleScreenLower => 0,
+        Mirroring::SingleScreenUpper => 1,
+        Mirroring::FourScreen => table,
+    };
+
+    physical * 0x400 + offset
+}
+
+fn palette_index(addr: u16) -> usize {
+    let addr = (addr & 0x1F) as usize;
+
+    // the backdrop entries of the sprite palettes mirror the background ones
+    match addr {
+        0x10 | 0x14 | 0x18 | 0x1C => addr - 0x10,
+        _ => addr,
+    }
+}
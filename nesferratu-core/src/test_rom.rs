@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+use crate::Emulator;
+use crate::cpu::CpuError;
+
+// test ROMs following blargg's protocol report through the PRG RAM at $6000,
+// see https://github.com/christopherpow/nes-test-roms/blob/master/instr_test-v5/readme.txt
+const STATUS: u16 = 0x6000;
+const SIGNATURE: u16 = 0x6001;
+const SIGNATURE_BYTES: [u8; 3] = [0xDE, 0xB0, 0x61];
+const TEXT: u16 = 0x6004;
+
+const STATUS_RUNNING: u8 = 0x80;
+const STATUS_RESET: u8 = 0x81;
+
+// the ROM asks for the reset button to be pressed after at least 100ms
+const RESET_DELAY_FRAMES: u64 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TestRomStatus {
+    Passed,
+    Failed(u8),         // result code written to $6000
+    Timeout,            // still running or never wrote a valid signature
+    Crashed(CpuError),  // the CPU jammed before the ROM reported a result
+}
+
+impl Display for TestRomStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestRomStatus::Passed => write!(f, "passed"),
+            TestRomStatus::Failed(code) => write!(f, "failed ({})", code),
+            TestRomStatus::Timeout => write!(f, "timeout"),
+            TestRomStatus::Crashed(e) => write!(f, "crashed ({})", e),
+        }
+    }
+}
+
+pub struct TestRomResult {
+    pub status: TestRomStatus,
+    pub message: String,    // text printed to $6004 by the ROM
+    pub frames: u64,
+}
+
+impl TestRomResult {
+    pub fn passed(&self) -> bool {
+        self.status == TestRomStatus::Passed
+    }
+}
+
+// runs the ROM without any output until it reports a result or max_frames have passed
+pub fn run_test_rom(emu: &mut Emulator, max_frames: u64) -> TestRomResult {
+    let mut reset_frame = None;
+    let mut status = TestRomStatus::Timeout;
+    let mut frames = 0;
+
+    while frames < max_frames {
+        let result = emu.run_frame();
+        frames += 1;
+
+        if let Err(e) = result {
+            status = TestRomStatus::Crashed(e);
+            break;
+        }
+
+        if let Some(frame) = reset_frame {
+            if frames >= frame {
+                emu.reset();
+                reset_frame = None;
+            }
+            continue;
+        }
+
+        if !has_signature(emu) {
+            continue;
+        }
+
+        match emu.peek_cpu(STATUS) {
+            STATUS_RUNNING => {}
+            STATUS_RESET => reset_frame = Some(frames + RESET_DELAY_FRAMES),
+            0 => {
+                status = TestRomStatus::Passed;
+                break;
+            }
+            code => {
+                status = TestRomStatus::Failed(code);
+                break;
+            }
+        }
+    }
+
+    TestRomResult {
+        status,
+        message: read_text(emu),
+        frames,
+    }
+}
+
+fn has_signature(emu: &Emulator) -> bool {
+    SIGNATURE_BYTES.iter()
+        .enumerate()
+        .all(|(i, b)| emu.peek_cpu(SIGNATURE + i as u16) == *b)
+}
+
+// zero-terminated text, empty if the signature is missing
+fn read_text(emu: &Emulator) -> String {
+    if !has_signature(emu) {
+        return String::new();
+    }
+
+    let bytes: Vec<u8> = (TEXT..0x8000)
+        .map(|addr| emu.peek_cpu(addr))
+        .take_while(|b| *b != 0)
+        .collect();
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
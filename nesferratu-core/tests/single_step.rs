@@ -0,0 +1,254 @@
+// Runs the per-opcode JSON single step tests (https://github.com/SingleStepTests/65x02, nes6502 set)
+// against the CPU interpreter on a flat 64K bus.
+//
+// The full set of test vectors is not part of the repository. Put the `xx.json` files into
+// `tests/single_step/` or point NESFERRATU_SINGLE_STEP_DIR to their directory and run
+// `cargo test --test single_step -- --ignored --nocapture` to see the per-opcode report.
+//
+// `single_step_regressions.json` holds hand-written cases in the same format for the flag and
+// stack bugs the full set found, the unofficial opcodes and the dummy reads and writes of indexed and
+// read-modify-write instructions, those run with every `cargo test`.
+// Cases with `"jam": true` expect the instruction to jam the CPU instead of finishing.
+
+use std::{env, fs, path::PathBuf};
+
+use serde_json::Value;
+
+use nesferratu_core::BusMessage;
+use nesferratu_core::cpu::{CPU, CpuError, CpuInterpreter, instructions::Opcode};
+use nesferratu_core::debugger::CpuDebugger;
+
+use num_traits::FromPrimitive;
+
+// give up on instructions that run for longer than any real one
+const MAX_CYCLES: usize = 16;
+
+#[derive(Debug, PartialEq)]
+enum Cycle {
+    Read(u16, u8),
+    Write(u16, u8),
+    Nop,
+}
+
+#[derive(Debug, PartialEq)]
+struct State {
+    pc: u16,
+    s: u8,
+    a: u8,
+    x: u8,
+    y: u8,
+    p: u8,
+    ram: Vec<(u16, u8)>,
+}
+
+impl State {
+    fn from_json(v: &Value) -> State {
+        let num = |key: &str| v[key].as_u64().unwrap_or_else(|| panic!("missing field {}", key));
+
+        State {
+            pc: num("pc") as u16,
+            s: num("s") as u8,
+            a: num("a") as u8,
+            x: num("x") as u8,
+            y: num("y") as u8,
+            p: num("p") as u8,
+            ram: v["ram"].as_array()
+                .expect("missing field ram")
+                .iter()
+                .map(|e| (e[0].as_u64().unwrap() as u16, e[1].as_u64().unwrap() as u8))
+                .collect(),
+        }
+    }
+}
+
+fn cycles_from_json(v: &Value) -> Vec<Cycle> {
+    v.as_array()
+        .expect("missing field cycles")
+        .iter()
+        .map(|c| {
+            let addr = c[0].as_u64().unwrap() as u16;
+            let data = c[1].as_u64().unwrap() as u8;
+            match c[2].as_str() {
+                Some("read") => Cycle::Read(addr, data),
+                Some("write") => Cycle::Write(addr, data),
+                other => panic!("unknown cycle type {:?}", other),
+            }
+        })
+        .collect()
+}
+
+fn vector_dir() -> PathBuf {
+    match env::var_os("NESFERRATU_SINGLE_STEP_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("single_step"),
+    }
+}
+
+// runs one test case, returns a description of the first mismatch
+fn run_test(test: &Value) -> Result<(), String> {
+    let initial = State::from_json(&test["initial"]);
+    let expected = State::from_json(&test["final"]);
+    let expected_cycles = cycles_from_json(&test["cycles"]);
+    let jam = test["jam"].as_bool().unwrap_or(false);
+
+    let mut ram = vec![0u8; 0x10000];
+    let mut cpu = CpuInterpreter::new();
+
+    // run the reset sequence, it reads zeros from the bus
+    cpu.reset();
+    let mut data = None;
+    loop {
+        let msg = cpu.clock(data).expect("the reset sequence can't fail");
+        data = match msg {
+            BusMessage::Read{..} => Some(0),
+            _ => None,
+        };
+        if cpu.get_emulation_state().instruction_done {
+            break;
+        }
+    }
+
+    {
+        let regs = cpu.get_cup_regs_mut().expect("registers must be writable between instructions");
+        regs.pc = initial.pc;
+        regs.sp = initial.s;
+        regs.a = initial.a;
+        regs.x = initial.x;
+        regs.y = initial.y;
+        regs.status = initial.p;
+    }
+
+    for &(addr, value) in initial.ram.iter() {
+        ram[addr as usize] = value;
+    }
+
+    // the interpreter already requested the opcode at the end of the reset sequence
+    let mut cycles = vec![Cycle::Read(initial.pc, ram[initial.pc as usize])];
+    let mut data = Some(ram[initial.pc as usize]);
+
+    loop {
+        let msg = match cpu.clock(data) {
+            Ok(msg) => msg,
+            Err(CpuError::Jammed{..}) if jam => break,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        if cpu.get_emulation_state().instruction_done {
+            break;
+        }
+
+        if cycles.len() >= MAX_CYCLES {
+            return Err(format!("instruction did not finish after {} cycles", MAX_CYCLES));
+        }
+
+        data = None;
+        match msg {
+            BusMessage::Read{addr} => {
+                data = Some(ram[addr as usize]);
+                cycles.push(Cycle::Read(addr, ram[addr as usize]));
+            }
+            BusMessage::Write{addr, data} => {
+                ram[addr as usize] = data;
+                cycles.push(Cycle::Write(addr, data));
+            }
+            BusMessage::Nop => cycles.push(Cycle::Nop),
+        }
+    }
+
+    if jam {
+        // a jammed CPU stays off the bus until the next reset
+        for _ in 0..3 {
+            match cpu.clock(None) {
+                Ok(BusMessage::Nop) => {}
+                other => return Err(format!("jammed CPU returned {:?}", other)),
+            }
+        }
+    } else if cpu.get_emulation_state().jam.is_some() {
+        return Err("the CPU jammed".to_owned());
+    }
+
+    let regs = cpu.get_cpu_regs();
+    let result = State {
+        pc: regs.pc,
+        s: regs.sp,
+        a: regs.a,
+        x: regs.x,
+        y: regs.y,
+        p: regs.status,
+        ram: expected.ram.iter().map(|&(addr, _)| (addr, ram[addr as usize])).collect(),
+    };
+
+    if result != expected {
+        return Err(format!("state mismatch\n  expected {:02X?}\n  got      {:02X?}", expected, result));
+    }
+
+    if cycles != expected_cycles {
+        return Err(format!("bus mismatch\n  expected {:04X?}\n  got      {:04X?}", expected_cycles, cycles));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn regressions() {
+    let tests: Vec<Value> = serde_json::from_str(include_str!("single_step_regressions.json"))
+        .expect("could not parse single_step_regressions.json");
+
+    let failures: Vec<String> = tests.iter()
+        .filter_map(|test| run_test(test).err().map(|e| format!("{}: {}", test["name"], e)))
+        .collect();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+#[ignore = "needs the SingleStepTests vectors, see the top of this file"]
+fn single_step() {
+    let dir = vector_dir();
+    assert!(dir.is_dir(), "single step test vectors not found in {}", dir.display());
+
+    let mut failed_opcodes = Vec::new();
+
+    for byte in 0..=255u8 {
+        let path = dir.join(format!("{:02x}.json", byte));
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(_) => continue,
+        };
+
+        let opcode = Opcode::from_u8(byte).expect("the opcode table covers all 256 opcodes");
+        let instruction = opcode.to_instruction();
+
+        // KIL jams the CPU, there is no end of the instruction to compare against
+        if instruction.mnemonic == "KIL" {
+            println!("{:02X} {:?}: skipped", byte, opcode);
+            continue;
+        }
+
+        let tests: Vec<Value> = serde_json::from_str(&text)
+            .unwrap_or_else(|e| panic!("could not parse {}: {}", path.display(), e));
+
+        let mut passed = 0;
+        let mut first_failure = None;
+
+        for test in tests.iter() {
+            match run_test(test) {
+                Ok(()) => passed += 1,
+                Err(e) => {
+                    if first_failure.is_none() {
+                        first_failure = Some(format!("{}: {}", test["name"], e));
+                    }
+                }
+            }
+        }
+
+        println!("{:02X} {:?}: {}/{} passed", byte, opcode, passed, tests.len());
+
+        if let Some(failure) = first_failure {
+            println!("  {}", failure);
+            failed_opcodes.push(format!("{:02X}", byte));
+        }
+    }
+
+    assert!(failed_opcodes.is_empty(), "opcodes with failing tests: {}", failed_opcodes.join(", "));
+}
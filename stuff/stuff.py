@@ -10,9 +10,11 @@ cyclelen = csv.reader(open("cyclelength.tsv"), delimiter="\t")
 
 table = [[{} for _ in range(16) ] for _ in range(16)]
 
+# unofficial opcodes are marked with a leading '*'
 for i, row in enumerate(opcodes):
     for j, cell in enumerate(row):
-        table[j][i]['opcode'] = cell
+        table[j][i]['opcode'] = cell.lstrip('*')
+        table[j][i]['unofficial'] = cell.startswith('*')
 
 for i, row in enumerate(addressing):
     for j, cell in enumerate(row):
@@ -59,6 +61,12 @@ adrs = {
     '(ind), y+': 'ind_y_extra',
 }
 
+# opcodes whose addressing can't be derived from the tables
+# JSR reads the high byte of its target only after pushing the return address
+addr_overrides = {
+    0x20: 'abs_jsr',
+}
+
 full_adrs = {
     'accum': 'Accum',
     'imm': 'IMM',
@@ -92,22 +100,45 @@ def operand(addrname):
 def funname(op):
     return op['opcode'].lower() + '_' + operand(addrname(op['addressing']))
 
-def opname(op):
+def basename(op):
     return op['opcode'].upper() + '_' + addrname(op['addressing'])
 
+# unofficial opcodes that share mnemonic and addressing with another opcode get their byte appended
+def opnames(table):
+    counts = {}
+    for row in table:
+        for op in row:
+            if op and op['opcode']:
+                counts[basename(op)] = counts.get(basename(op), 0) + 1
+
+    names = {}
+    for j in range(len(table)):
+        for i in range(len(table[0])):
+            op = table[i][j]
+            if op and op['opcode']:
+                name = basename(op)
+                if op['unofficial'] and counts[name] > 1:
+                    name += '_' + hex(j)[2] + hex(i)[2]
+                names[(j, i)] = name
+    return names
+
 def create_enum(table):
+    names = opnames(table)
+    print('#[derive(num_derive::FromPrimitive, Debug, Copy, Clone)]')
+    print('#[allow(non_camel_case_types)]')
     print('#[repr(u8)]')
-    print('pub enum Opcodes {')
+    print('pub enum Opcode {')
     for j in range(len(table)):
         for i in range(len(table[0])):
             op = table[i][j]
             if op and op['opcode']:
-                name = opname(op)
+                name = names[(j, i)]
                 byte = hex(j) + hex(i)[2]
                 print(f'    {name} = {byte},')
     print('}')
 
 def create_opmatch(table):
+    names = opnames(table)
     print('match self {')
     
     for j in range(len(table)):
@@ -115,16 +146,18 @@ def create_opmatch(table):
             op = table[i][j]
             if op and op['opcode']:
                 addr_postfix = '_extra' if op['cyclelen'].endswith('*') and op['addressing'] != 'relative' else ''
+                addr_delegate = addr_overrides.get(j * 16 + i, addrname(op['addressing']) + addr_postfix)
 
                 arm = (
-                    f'    \n    Opcode::{opname(op)} => {{\n'
+                    f'    \n    Opcode::{names[(j, i)]} => {{\n'
                     f'        &Instruction{{\n'
                     f'            cycles: {op["cyclelen"].strip("*")},\n'
                     f'            bytes: {op["bytelen"]},\n'
-                    f'            addr_delegate: addressing::{addrname(op["addressing"])+addr_postfix},\n'
+                    f'            addr_delegate: addressing::{addr_delegate},\n'
                     f'            op_delegate: OpDelegate::{operand(addrname(op["addressing"]))}(ops::{funname(op).lower()}),\n'
                     f'            mnemonic: "{op["opcode"].upper()}",\n'
                     f'            addressing: "{fulladdrname(op["addressing"])}",\n'
+                    f'            unofficial: {str(op["unofficial"]).lower()},\n'
                     f'        }}\n'
                     f'    }}'
                 )